@@ -0,0 +1,442 @@
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, LitInt, LitStr, Path, Token, Type, WherePredicate};
+
+/// the options given to the derive with `#[expect_macro(...)]` on the enum.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// `#[expect_macro(assertions)]`: generate an `assert_{variant_name}` method for each variant.
+    pub assertions: bool,
+    /// `#[expect_macro(ref_enum)]`: generate a `{enum_name}Ref` borrowed view of the enum.
+    pub ref_enum: bool,
+    /// `#[expect_macro(projection)]`: generate a `{enum_name}Projection` borrowed view of the enum, built by `project(&self)`.
+    pub projection: bool,
+    /// `#[expect_macro(try_as)]`: generate a `try_as_{variant_name}(&self)` method for each variant, and an
+    /// `{enum_name}VariantMismatch` error.
+    pub try_as: bool,
+    /// `#[expect_macro(as_ref)]`: generate an `as_{variant_name}(&self)` method for each variant.
+    pub as_ref: bool,
+    /// `#[expect_macro(as_mut)]`: generate an `as_mut_{variant_name}(&mut self)` method for each variant.
+    pub as_mut: bool,
+    /// `#[expect_macro(pin_projections)]`: generate an `as_{variant_name}_pin(self: Pin<&mut Self>)` method for each variant.
+    pub pin_projections: bool,
+    /// `#[expect_macro(deref)]`: implement `Deref` to the field for an enum with a single variant with a single field.
+    pub deref: bool,
+    /// `#[expect_macro(is)]`: generate an `is_{variant_name}(&self)` method for each variant.
+    pub is: bool,
+    /// `#[expect_macro(unwrap)]`: generate an `unwrap_{variant_name}(self)` method for each variant.
+    pub unwrap: bool,
+    /// `#[expect_macro(anyhow)]`: the non-panicking guarded methods return an `anyhow::Result`.
+    pub anyhow: bool,
+    /// `#[expect_macro(impl_on = "...")]`: the methods are also generated on this wrapper type, forwarding to the enum.
+    pub impl_on: Option<Type>,
+    /// `#[expect_macro(bound = "...")]`: the predicates added to the where clause of the generated impl.
+    pub bounds: Vec<WherePredicate>,
+    /// `#[expect_macro(result_ok_variant = "...")]`: generate an `into_result(self)` method, this variant being the `Ok`.
+    pub result_ok_variant: Option<Ident>,
+    /// `#[expect_macro(module = "...")]`: generate the methods in a trait inside this module.
+    pub module: Option<Ident>,
+    /// `#[expect_macro(eq_ref)]`: the guarded methods take the expected values by reference.
+    pub eq_ref: bool,
+    /// `#[expect_macro(eq_impl)]`: the guarded methods take any value comparable to the fields, `impl PartialEq<T>`.
+    pub eq_impl: bool,
+    /// `#[expect_macro(eq_trait = "...")]`: the guarded methods compare the fields with a method of this trait instead of `==`.
+    pub eq_trait: Option<Path>,
+    /// `#[expect_macro(eq_method = "...")]`: the method of `eq_trait` comparing two values, its name in snake case by default.
+    pub eq_method: Option<Ident>,
+    /// `#[expect_macro(debug_trace)]`: in debug builds, the guarded methods print a note when only the values differ.
+    pub debug_trace: bool,
+    /// `#[expect_macro(tuple_args)]`: the guarded methods take the expected values as a single tuple.
+    pub tuple_args: bool,
+    /// `#[expect_macro(borrow_self)]`: `expect_{variant_name}` takes `&self` and returns clones of the fields.
+    pub borrow_self: bool,
+    /// `#[expect_macro(clone_on_extract)]`: every method returning the fields by value takes `&self` and clones them.
+    pub clone_on_extract: bool,
+    /// `#[expect_macro(prefix_by_arity)]`: the unit variants get an `is_{variant_name}` method instead of `expect_{variant_name}`.
+    pub prefix_by_arity: bool,
+    /// `#[expect_macro(panic_message_prefix = "...")]`: prepended to the messages of the generated panics.
+    pub panic_message_prefix: Option<String>,
+    /// `#[expect_macro(cloned_accessors)]`: generate an `expect_{variant_name}_cloned(&self)` method for each variant.
+    pub cloned_accessors: bool,
+    /// `#[expect_macro(mut_accessors)]`: generate an `expect_{variant_name}_mut(&mut self)` method for each variant.
+    pub mut_accessors: bool,
+    /// `#[expect_macro(contains)]`: generate a `contains_{variant_name}(&self, expected)` method for each variant with a single field.
+    pub contains: bool,
+    /// `#[expect_macro(predicate_accessors)]`: generate an `expect_{variant_name}_if(self, predicate)` method for each variant with fields.
+    pub predicate_accessors: bool,
+    /// `#[expect_macro(all)]`: every accessor family is enabled.
+    pub all: bool,
+    /// `#[expect_macro(take)]`: generate a `take_{variant_name}(&mut self)` method for each variant.
+    pub take: bool,
+    /// `#[expect_macro(sentinel = "...")]`: the variant left by `take_{variant_name}`, instead of the first unit variant.
+    pub sentinel: Option<Ident>,
+    /// `#[expect_macro(detailed)]`: generate an `expect_{variant_name}_detailed(self, ...)` method for each variant,
+    /// returning a `{enum_name}MatchResult`.
+    pub detailed: bool,
+    /// `#[expect_macro(or_self)]`: generate an `expect_{variant_name}_or_self(self, ...)` method for each variant,
+    /// returning `self` back in `Err` if it doesn't match.
+    pub or_self: bool,
+    /// `#[expect_macro(optional_guard)]`: the guarded methods take an `Option` for each compared field, `None` matching any value.
+    pub optional_guard: bool,
+    /// `#[expect_macro(with_methods)]`: generate a `with_{variant_name}(self, ...)` method for each variant.
+    pub with_methods: bool,
+    /// `#[expect_macro(or_default)]`: generate an `expect_{variant_name}_or_default(self)` method for each variant with fields.
+    pub or_default: bool,
+    /// `#[expect_macro(into)]`: generate an `into_{variant_name}(self)` method for each variant.
+    pub into: bool,
+    /// `#[expect_macro(future_accessors)]`: generate an `into_{variant_name}_future(self)` method for each variant with a
+    /// single field.
+    pub future_accessors: bool,
+    /// `#[expect_macro(into_iter)]`: generate an `into_iter_{variant_name}(self)` method for each `#[iter]` field.
+    pub into_iter: bool,
+    /// `#[expect_macro(prefix = "...")]`: the prefix of the `expect_{variant_name}` methods.
+    pub prefix: Option<String>,
+    /// `#[expect_macro(into_prefix = "...")]`: the prefix of the `into_{variant_name}` methods.
+    pub into_prefix: Option<String>,
+    /// `#[expect_macro(ref_prefix = "...")]`: the prefix of the `as_{variant_name}` methods.
+    pub ref_prefix: Option<String>,
+    /// `#[expect_macro(hidden)]`: the generated methods are `#[doc(hidden)]`.
+    pub hidden: bool,
+    /// `#[expect_macro(try_get)]`: generate a `try_get_{variant_name}(self)` method for each variant.
+    pub try_get: bool,
+    /// `#[expect_macro(getters)]`: generate a `get_{variant_name}_{field_name}(&self)` method for each named field.
+    pub getters: bool,
+    /// `#[expect_macro(setters)]`: generate a `set_{variant_name}_{field_name}(&mut self, value)` method for each named field.
+    pub setters: bool,
+    /// `#[expect_macro(by_type)]`: generate a `first_{type_name}(self)` method for each type of the single-field variants.
+    pub by_type: bool,
+    /// `#[expect_macro(discriminant_eq)]`: generate an `eq_variant(&self, other)` method.
+    pub discriminant_eq: bool,
+    /// `#[common_field(...)]`: generate a `{field_name}(&self)` method for each of these fields, shared by several variants.
+    pub common_fields: Vec<Ident>,
+    /// `#[expect_macro(as_discriminant)]`: generate an `as_discriminant(&self)` method.
+    pub as_discriminant: bool,
+    /// `#[expect_macro(discriminant_index)]`: generate a `discriminant_index(&self)` method.
+    pub discriminant_index: bool,
+    /// `#[expect_macro(variant_rank)]`: generate a `variant_rank(&self)` method and a `by_variant(a, b)` comparison.
+    pub variant_rank: bool,
+    /// `#[expect_macro(variant_count)]`: generate a `VARIANT_COUNT` associated const.
+    pub variant_count: bool,
+    /// `#[expect_macro(variants_with_data)]`: generate a `variants_with_data()` function listing the variants with fields.
+    pub variants_with_data: bool,
+    /// `#[expect_macro(assert_count = 3)]`: the number of variants the enum must have.
+    pub assert_count: Option<LitInt>,
+    /// `#[expect_macro(payload_type_name)]`: generate a `payload_type_name(&self)` method.
+    pub payload_type_name: bool,
+    /// `#[expect_macro(repr_code)]`: generate a `code(&self)` method returning the discriminant, for a `#[repr(...)]` enum.
+    pub repr_code: bool,
+}
+
+impl ContainerAttrs {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut container = ContainerAttrs::default();
+        // whether `all` or `only(...)` was already given.
+        let mut families = false;
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("expect_macro")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("assertions") {
+                    container.assertions = true;
+                    Ok(())
+                } else if meta.path.is_ident("ref_enum") {
+                    container.ref_enum = true;
+                    Ok(())
+                } else if meta.path.is_ident("projection") {
+                    container.projection = true;
+                    Ok(())
+                } else if meta.path.is_ident("try_as") {
+                    container.try_as = true;
+                    Ok(())
+                } else if meta.path.is_ident("as_ref") {
+                    container.as_ref = true;
+                    Ok(())
+                } else if meta.path.is_ident("eq_ref") {
+                    container.eq_ref = true;
+                    Ok(())
+                } else if meta.path.is_ident("eq_impl") {
+                    container.eq_impl = true;
+                    Ok(())
+                } else if meta.path.is_ident("debug_trace") {
+                    container.debug_trace = true;
+                    Ok(())
+                } else if meta.path.is_ident("tuple_args") {
+                    container.tuple_args = true;
+                    Ok(())
+                } else if meta.path.is_ident("borrow_self") {
+                    container.borrow_self = true;
+                    Ok(())
+                } else if meta.path.is_ident("clone_on_extract") {
+                    container.clone_on_extract = true;
+                    Ok(())
+                } else if meta.path.is_ident("prefix_by_arity") {
+                    container.prefix_by_arity = true;
+                    Ok(())
+                } else if meta.path.is_ident("panic_message_prefix") {
+                    let prefix: LitStr = meta.value()?.parse()?;
+                    container.panic_message_prefix = Some(prefix.value());
+                    Ok(())
+                } else if meta.path.is_ident("cloned_accessors") {
+                    container.cloned_accessors = true;
+                    Ok(())
+                } else if meta.path.is_ident("mut_accessors") {
+                    container.mut_accessors = true;
+                    Ok(())
+                } else if meta.path.is_ident("contains") {
+                    container.contains = true;
+                    Ok(())
+                } else if meta.path.is_ident("predicate_accessors") {
+                    container.predicate_accessors = true;
+                    Ok(())
+                } else if meta.path.is_ident("take") {
+                    container.take = true;
+                    Ok(())
+                } else if meta.path.is_ident("sentinel") {
+                    let sentinel: LitStr = meta.value()?.parse()?;
+                    container.sentinel = Some(sentinel.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("detailed") {
+                    container.detailed = true;
+                    Ok(())
+                } else if meta.path.is_ident("or_self") {
+                    container.or_self = true;
+                    Ok(())
+                } else if meta.path.is_ident("optional_guard") {
+                    container.optional_guard = true;
+                    Ok(())
+                } else if meta.path.is_ident("with_methods") {
+                    container.with_methods = true;
+                    Ok(())
+                } else if meta.path.is_ident("or_default") {
+                    container.or_default = true;
+                    Ok(())
+                } else if meta.path.is_ident("future_accessors") {
+                    container.future_accessors = true;
+                    Ok(())
+                } else if meta.path.is_ident("into_iter") {
+                    container.into_iter = true;
+                    Ok(())
+                } else if meta.path.is_ident("into") {
+                    container.into = true;
+                    Ok(())
+                } else if meta.path.is_ident("prefix") {
+                    container.prefix = Some(prefix(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("into_prefix") {
+                    container.into_prefix = Some(prefix(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("ref_prefix") {
+                    container.ref_prefix = Some(prefix(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("as_mut") {
+                    container.as_mut = true;
+                    Ok(())
+                } else if meta.path.is_ident("deref") {
+                    container.deref = true;
+                    Ok(())
+                } else if meta.path.is_ident("pin_projections") {
+                    container.pin_projections = true;
+                    Ok(())
+                } else if meta.path.is_ident("is") {
+                    container.is = true;
+                    Ok(())
+                } else if meta.path.is_ident("unwrap") {
+                    container.unwrap = true;
+                    Ok(())
+                } else if meta.path.is_ident("hidden") {
+                    container.hidden = true;
+                    Ok(())
+                } else if meta.path.is_ident("try_get") {
+                    container.try_get = true;
+                    Ok(())
+                } else if meta.path.is_ident("getters") {
+                    container.getters = true;
+                    Ok(())
+                } else if meta.path.is_ident("setters") {
+                    container.setters = true;
+                    Ok(())
+                } else if meta.path.is_ident("by_type") {
+                    container.by_type = true;
+                    Ok(())
+                } else if meta.path.is_ident("discriminant_eq") {
+                    container.discriminant_eq = true;
+                    Ok(())
+                } else if meta.path.is_ident("as_discriminant") {
+                    container.as_discriminant = true;
+                    Ok(())
+                } else if meta.path.is_ident("discriminant_index") {
+                    container.discriminant_index = true;
+                    Ok(())
+                } else if meta.path.is_ident("variant_rank") {
+                    container.variant_rank = true;
+                    Ok(())
+                } else if meta.path.is_ident("variant_count") {
+                    container.variant_count = true;
+                    Ok(())
+                } else if meta.path.is_ident("variants_with_data") {
+                    container.variants_with_data = true;
+                    Ok(())
+                } else if meta.path.is_ident("assert_count") {
+                    container.assert_count = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("payload_type_name") {
+                    container.payload_type_name = true;
+                    Ok(())
+                } else if meta.path.is_ident("repr_code") {
+                    container.repr_code = true;
+                    Ok(())
+                } else if meta.path.is_ident("serde_untagged_helper") {
+                    container.as_ref = true;
+                    container.is = true;
+                    Ok(())
+                } else if meta.path.is_ident("anyhow") {
+                    container.anyhow = true;
+                    Ok(())
+                } else if meta.path.is_ident("all") || meta.path.is_ident("only") {
+                    if families {
+                        return Err(meta.error("`all` and `only(...)` can only be used once"));
+                    }
+                    families = true;
+
+                    if meta.path.is_ident("all") {
+                        container.all = true;
+                        for family in FAMILIES {
+                            *container.family(family).unwrap() = true;
+                        }
+                        Ok(())
+                    } else {
+                        meta.parse_nested_meta(|family| {
+                            let enabled = family.path.get_ident().and_then(|ident| container.family(&ident.to_string()));
+                            match enabled {
+                                Some(enabled) => {
+                                    *enabled = true;
+                                    Ok(())
+                                },
+                                None => Err(family.error(format!("unknown accessor family, expected one of {}", FAMILIES.join(", ")))),
+                            }
+                        })
+                    }
+                } else if meta.path.is_ident("bound") {
+                    let bounds: LitStr = meta.value()?.parse()?;
+                    container.bounds.extend(bounds.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?);
+                    Ok(())
+                } else if meta.path.is_ident("impl_on") {
+                    let wrapper: LitStr = meta.value()?.parse()?;
+                    container.impl_on = Some(wrapper.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("result_ok_variant") {
+                    let variant: LitStr = meta.value()?.parse()?;
+                    container.result_ok_variant = Some(variant.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("eq_trait") {
+                    let eq_trait: LitStr = meta.value()?.parse()?;
+                    container.eq_trait = Some(eq_trait.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("eq_method") {
+                    let method: LitStr = meta.value()?.parse()?;
+                    container.eq_method = Some(method.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("module") {
+                    let module: LitStr = meta.value()?.parse()?;
+                    container.module = Some(module.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `expect_macro` option"))
+                }
+            })?;
+        }
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("common_field")) {
+            container.common_fields.extend(attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?);
+        }
+
+        // the wrapper can't be pinned in place of the enum, `self: Pin<&mut Self>` isn't forwarded to it.
+        if let (Some(wrapper), true) = (&container.impl_on, container.pin_projections) {
+            return Err(syn::Error::new_spanned(wrapper, "`impl_on` can't be used with `pin_projections`"));
+        }
+
+        Ok(container)
+    }
+
+    /// the flag enabling an accessor family, by the name used in `only(...)`.
+    fn family(&mut self, name: &str) -> Option<&mut bool> {
+        match name {
+            "assertions" => Some(&mut self.assertions),
+            "as_ref" => Some(&mut self.as_ref),
+            "as_mut" => Some(&mut self.as_mut),
+            "is" => Some(&mut self.is),
+            "cloned_accessors" => Some(&mut self.cloned_accessors),
+            "mut_accessors" => Some(&mut self.mut_accessors),
+            "contains" => Some(&mut self.contains),
+            "predicate_accessors" => Some(&mut self.predicate_accessors),
+            "or_default" => Some(&mut self.or_default),
+            "into" => Some(&mut self.into),
+            "unwrap" => Some(&mut self.unwrap),
+            "try_get" => Some(&mut self.try_get),
+            "getters" => Some(&mut self.getters),
+            "setters" => Some(&mut self.setters),
+            "take" => Some(&mut self.take),
+            "with_methods" => Some(&mut self.with_methods),
+            "or_self" => Some(&mut self.or_self),
+            "try_as" => Some(&mut self.try_as),
+            _ => None,
+        }
+    }
+}
+
+/// the accessor families enabled by `#[expect_macro(all)]`.
+const FAMILIES: [&str; 18] = [
+    "assertions", "as_ref", "as_mut", "is", "contains", "cloned_accessors", "mut_accessors", "predicate_accessors",
+    "or_default", "into", "unwrap", "try_get", "getters", "setters", "take", "with_methods", "or_self", "try_as",
+];
+
+/// the options given to the derive with `#[expect_macro(...)]` on a variant.
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    /// `#[expect_macro(construct)]`: generate a `{variant_name}(...) -> Self` constructor.
+    pub construct: bool,
+    /// `#[expect_macro(no_construct)]`: the panic messages don't build the expected value.
+    pub no_construct: bool,
+    /// `#[expect_macro(rename_method = "...")]`: the name of `expect_{variant_name}`.
+    pub rename_method: Option<Ident>,
+    /// `#[expect_macro(group = "...")]`: the groups of the variant, each with an `is_{group}` method.
+    pub groups: Vec<Ident>,
+}
+
+impl VariantAttrs {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut variant = VariantAttrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("expect_macro")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("construct") {
+                    variant.construct = true;
+                    Ok(())
+                } else if meta.path.is_ident("no_construct") {
+                    variant.no_construct = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename_method") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    variant.rename_method = Some(name.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("group") {
+                    let group: LitStr = meta.value()?.parse()?;
+                    variant.groups.push(group.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `expect_macro` option for a variant"))
+                }
+            })?;
+        }
+
+        Ok(variant)
+    }
+}
+
+/// a method prefix, `prefix = "..."`, which must be usable at the start of an identifier.
+fn prefix(meta: &ParseNestedMeta) -> syn::Result<String> {
+    let prefix: LitStr = meta.value()?.parse()?;
+    if syn::parse_str::<Ident>(&format!("{}_", prefix.value())).is_err() {
+        return Err(syn::Error::new_spanned(prefix, "expected a prefix usable in a method name"));
+    }
+    Ok(prefix.value())
+}
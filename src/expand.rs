@@ -0,0 +1,1708 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Ident, ImplItemFn, Type};
+
+use crate::attr::ContainerAttrs;
+use crate::{impl_on, module, ref_enum};
+use crate::variant::{Field, ReturnStruct, Style, Variant};
+
+/// the lifetime given to `&self` by the methods returning references, so it can't collide with the lifetimes of the enum.
+pub(crate) const LIFETIME: &str = "'__expect";
+
+/// `tokens` with every `Self` replaced by `with`, for the types used outside of the impl of the enum.
+pub(crate) fn replace_self(tokens: TokenStream, with: &TokenStream) -> TokenStream {
+    tokens.into_iter().map(|token| match token {
+        TokenTree::Ident(ident) if ident == "Self" => with.clone(),
+        TokenTree::Group(group) => {
+            let mut replaced = Group::new(group.delimiter(), replace_self(group.stream(), with));
+            replaced.set_span(group.span());
+            TokenTree::Group(replaced).into()
+        },
+        token => token.into(),
+    }).collect()
+}
+
+pub(crate) fn derive(derive_input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &derive_input.ident;
+    let container = ContainerAttrs::from_attrs(&derive_input.attrs)?;
+
+    let variants = match &derive_input.data {
+        Data::Enum(e) => e.variants.iter().map(|variant| Variant::new(name, &derive_input.generics, variant)).collect::<syn::Result<Vec<_>>>()?,
+        _ => return Err(syn::Error::new_spanned(name, "Expect can only be derived for enums")),
+    };
+
+    if let Some(count) = &container.assert_count {
+        if count.base10_parse::<usize>()? != variants.len() {
+            let message = format!("the enum has {} variants, but `assert_count = {}`", variants.len(), count);
+            return Err(syn::Error::new_spanned(count, message));
+        }
+    }
+
+    if let Some(eq_trait) = &container.eq_trait {
+        if container.eq_impl {
+            return Err(syn::Error::new_spanned(eq_trait, "`eq_trait` can't be used with `eq_impl`"));
+        }
+    } else if let Some(method) = &container.eq_method {
+        return Err(syn::Error::new_spanned(method, "`eq_method` needs an `eq_trait = \"...\"`"));
+    }
+
+    check_struct_collisions(&container, name, &variants)?;
+
+    // with `all`, an enum without a unit variant to leave in place doesn't get the `take` family.
+    let has_unit_variant = variants.iter().any(|variant| matches!(variant.style, Style::Unit));
+    let sentinel = if container.take && (has_unit_variant || container.sentinel.is_some() || !container.all) {
+        Some(sentinel(&container, name, &variants)?)
+    } else {
+        None
+    };
+
+    let mut items = Vec::new();
+    let mut methods = Vec::new();
+    // private methods used by the generated ones, always in the inherent impl.
+    let mut helpers = Vec::new();
+    // associated consts, always in the inherent impl too since they can't be forwarded.
+    let mut consts = Vec::new();
+
+    for variant in &variants {
+        if let Some(return_struct) = &variant.return_struct {
+            items.push(return_struct_item(&derive_input, variant, return_struct));
+        }
+
+        // with `prefix_by_arity`, an explicit `rename_method` or a `#[panic]` keeps the guarded method of a unit variant.
+        if container.prefix_by_arity && matches!(variant.style, Style::Unit) && !variant.is_panic && variant.attrs.rename_method.is_none() {
+            if !container.is {
+                methods.push(is_method(name, variant));
+            }
+        } else {
+            methods.push(expect_method(name, &container, variant));
+        }
+
+        if container.detailed {
+            methods.push(detailed_method(name, &container, variant));
+        }
+
+        if container.or_self {
+            methods.push(or_self_method(name, &container, variant));
+        }
+
+        if let Some(indices) = &variant.extract {
+            methods.push(partial_method(name, &container, variant, indices));
+        }
+
+        if variant.attrs.construct {
+            methods.push(construct_method(variant)?);
+        }
+
+        if container.with_methods {
+            methods.push(with_method(variant));
+        }
+
+        if container.cloned_accessors {
+            methods.push(cloned_method(name, &container, variant));
+        }
+
+        if container.contains {
+            methods.extend(contains_method(name, variant));
+        }
+
+        if container.or_default && !variant.fields.is_empty() {
+            methods.push(or_default_method(name, &container, variant));
+        }
+
+        if container.predicate_accessors && !variant.fields.is_empty() {
+            methods.push(predicate_method(name, &container, variant));
+        }
+
+        if container.mut_accessors {
+            methods.push(mut_method(name, &container, variant));
+        }
+
+        if variant.collect {
+            methods.push(collect_method(name, &container, variant)?);
+        }
+
+        if container.assertions {
+            methods.push(assert_method(name, &container, variant));
+        }
+
+        if container.as_ref {
+            methods.push(as_ref_method(name, &container, variant));
+        }
+
+        if container.try_as {
+            methods.push(try_as_method(name, variant));
+        }
+
+        if container.as_mut {
+            methods.push(as_mut_method(name, variant));
+        }
+
+        if container.pin_projections {
+            methods.push(pin_method(name, &container, variant));
+        }
+
+        if let Some(sentinel) = &sentinel {
+            methods.push(take_method(name, variant, sentinel));
+        }
+
+        if container.is {
+            methods.push(is_method(name, variant));
+        }
+
+        if container.into {
+            methods.push(into_method(name, &container, variant));
+        }
+
+        if container.unwrap {
+            methods.push(unwrap_method(name, &container, variant));
+        }
+
+        if container.try_get {
+            methods.push(try_get_method(name, &container, variant));
+        }
+
+        if container.getters {
+            methods.extend(getter_methods(name, variant));
+        }
+
+        if container.setters {
+            methods.extend(setter_methods(name, variant));
+        }
+
+        methods.extend(iter_methods(name, variant, false));
+
+        if container.into_iter {
+            methods.extend(iter_methods(name, variant, true));
+        }
+
+        if container.future_accessors && variant.fields.len() == 1 {
+            methods.push(future_method(name, variant));
+        }
+    }
+
+    if container.pin_projections {
+        items.push(not_drop_item(&derive_input));
+        items.push(unpin_item(&derive_input, &variants));
+    }
+
+    if container.detailed {
+        items.push(match_result_item(&derive_input));
+    }
+
+    if container.try_as {
+        items.push(variant_mismatch_item(&derive_input));
+    }
+
+    if container.deref {
+        items.push(deref_item(&derive_input, &variants)?);
+    }
+
+    if container.by_type {
+        methods.extend(by_type_methods(name, &container, &variants));
+    }
+
+    if container.discriminant_eq {
+        methods.push(quote! {
+            pub fn eq_variant(&self, other: &Self) -> bool {
+                ::core::mem::discriminant(self) == ::core::mem::discriminant(other)
+            }
+        });
+    }
+
+    if container.as_discriminant {
+        methods.push(quote! {
+            pub fn as_discriminant(&self) -> ::core::mem::Discriminant<Self> {
+                ::core::mem::discriminant(self)
+            }
+        });
+    }
+
+    for field_name in &container.common_fields {
+        methods.push(common_field_method(name, &variants, field_name)?);
+    }
+
+    if container.discriminant_index {
+        methods.push(discriminant_index_method(name, &variants));
+    }
+
+    if container.variants_with_data {
+        let names = variants.iter().filter(|variant| !matches!(variant.style, Style::Unit)).map(|variant| variant.ident.to_string());
+        methods.push(quote! {
+            pub fn variants_with_data() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+        });
+    }
+
+    methods.extend(group_methods(name, &variants));
+
+    if let Some(ok_variant) = &container.result_ok_variant {
+        methods.push(into_result_method(name, &variants, ok_variant)?);
+    }
+
+    if container.variant_rank {
+        methods.extend(variant_rank_methods(name, &variants));
+    }
+
+    if container.variant_count {
+        let count = variants.len();
+        consts.push(quote! {
+            pub const VARIANT_COUNT: usize = #count;
+        });
+    }
+
+    if container.payload_type_name {
+        methods.push(payload_type_name_method(name, &variants));
+    }
+
+    if container.repr_code {
+        methods.push(repr_code_method(&derive_input, &variants)?);
+    }
+
+    let no_debug_panics = variants.iter().any(|variant| variant.no_debug && (variant.is_panic || container.unwrap));
+    if container.assertions || container.try_get || container.try_as || no_debug_panics {
+        helpers.push(variant_name_method(name, &variants));
+    }
+
+    if container.ref_enum {
+        let (ref_enum, method) = ref_enum::ref_enum(&derive_input, &variants, &ref_enum::REF_ENUM)?;
+        items.push(ref_enum);
+        methods.push(method);
+    }
+
+    if container.projection {
+        let (projection, method) = ref_enum::ref_enum(&derive_input, &variants, &ref_enum::PROJECTION)?;
+        items.push(projection);
+        methods.push(method);
+    }
+
+    check_collisions(&methods)?;
+
+    if container.hidden {
+        methods = methods.into_iter().map(|method| quote! { #[doc(hidden)] #method }).collect();
+        consts = consts.into_iter().map(|item| quote! { #[doc(hidden)] #item }).collect();
+    }
+
+    // the impls get the extra bounds, the generated types keep the generics of the enum.
+    let mut impl_input = derive_input.clone();
+    if !container.bounds.is_empty() {
+        impl_input.generics.make_where_clause().predicates.extend(container.bounds.iter().cloned());
+    }
+
+    if let Some(wrapper) = &container.impl_on {
+        if container.module.is_some() {
+            return Err(syn::Error::new_spanned(wrapper, "`impl_on` can't be used with `module`"));
+        }
+        items.push(impl_on::forward_impl(&impl_input, wrapper, &methods)?);
+    }
+
+    if let Some(module) = &container.module {
+        items.push(module::ext_module(&impl_input, module, &methods)?);
+        methods.clear();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = impl_input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#consts)*
+            #(#methods)*
+            #(#helpers)*
+        }
+
+        #(#items)*
+    })
+}
+
+/// `expect_{variant_name}(self, fields...)`, returns the fields if `self` is the variant
+/// and they are equal to the arguments.
+fn expect_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = match &variant.attrs.rename_method {
+        Some(fn_name) => fn_name.clone(),
+        None => variant.method_name(container.prefix.as_deref().unwrap_or("expect")),
+    };
+    // with `borrow_self` or `clone_on_extract`, it is the same method as `expect_{variant_name}_cloned`.
+    guarded_method(name, container, variant, &fn_name, container.borrow_self || container.clone_on_extract)
+}
+
+/// `expect_{variant_name}_cloned(&self, fields...)`, like `expect_{variant_name}` but clones the fields
+/// instead of consuming `self`.
+fn cloned_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_cloned", variant.method_name(container.prefix.as_deref().unwrap_or("expect")));
+    guarded_method(name, container, variant, &fn_name, true)
+}
+
+/// `contains_{variant_name}(&self, expected)`, returns whether `self` is the variant and its field is `expected`,
+/// for the variants with a single field or a single `#[extract(...)]` field.
+fn contains_method(name: &Ident, variant: &Variant) -> Option<TokenStream> {
+    let index = match (variant.fields.len(), variant.extract.as_deref()) {
+        (_, Some(&[index])) => index,
+        (1, None) => 0,
+        _ => return None,
+    };
+
+    let fn_name = variant.method_name("contains");
+    let pattern = variant.partial_pattern(name, &[index]);
+    let field = &variant.fields[index];
+    let binding = &field.binding;
+    let ty = &field.ty;
+
+    Some(quote! {
+        pub fn #fn_name(&self, expected: &#ty) -> bool
+        where
+            for<'__eq> #ty: ::core::cmp::PartialEq,
+        {
+            match self {
+                #pattern => #binding == expected,
+                _ => false
+            }
+        }
+    })
+}
+
+/// `expect_{variant_name}_or_default(self)`, returns the fields if `self` is the variant, and their default values otherwise.
+fn or_default_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_or_default", variant.method_name(container.prefix.as_deref().unwrap_or("expect")));
+    let pattern = variant.pattern(name);
+    let returned_ty = variant.returned_ty();
+    let receiver = extract_receiver(container);
+    let returned = variant.returned(extracted(container, variant));
+    let defaults = variant.returned(variant.fields.iter().map(|_| quote! { ::core::default::Default::default() }));
+    let fields_ty = variant.fields.iter().map(|field| &field.ty);
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name(#receiver) -> #returned_ty
+        where
+            #(for<'__default> #fields_ty: ::core::default::Default),*
+        {
+            match self {
+                #pattern => #returned,
+                _ => #defaults
+            }
+        }
+    }
+}
+
+/// `expect_{variant_name}_if(self, predicate)`, like `expect_{variant_name}` but the fields are given by reference
+/// to `predicate` instead of being compared.
+fn predicate_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_if", variant.method_name(container.prefix.as_deref().unwrap_or("expect")));
+    let pattern = variant.pattern(name);
+    let bindings = variant.fields.iter().map(|field| &field.binding).collect::<Vec<_>>();
+    let fields_ty = variant.fields.iter().map(|field| &field.ty);
+    let predicate = quote! { predicate: impl ::core::ops::FnOnce(#(&#fields_ty),*) -> bool };
+    let returned_ty = variant.returned_ty();
+    let receiver = extract_receiver(container);
+    let returned = variant.returned(extracted(container, variant));
+    let expected = format!("expected {}::{}", name, variant.ident);
+
+    if variant.is_panic {
+        // `self` is checked first, so it can still be shown if the predicate doesn't hold.
+        let (found, value) = found(variant);
+        let panic = panic_call(container, variant, &format!("{}, found {}", expected, found), value);
+
+        quote! {
+            #[track_caller]
+            pub fn #fn_name(#receiver, #predicate) -> #returned_ty {
+                let matched = match &self {
+                    #pattern => predicate(#(#bindings),*),
+                    _ => false
+                };
+                if !matched {
+                    #panic;
+                }
+                match self {
+                    #pattern => #returned,
+                    _ => unreachable!()
+                }
+            }
+        }
+    } else {
+        let (returned_ty, returned, mismatch) = if container.anyhow {
+            (quote! { ::anyhow::Result<#returned_ty> }, quote! { Ok(#returned) }, quote! { Err(::anyhow::anyhow!(#expected)) })
+        } else {
+            (quote! { Option<#returned_ty> }, quote! { Some(#returned) }, quote! { None })
+        };
+        // the fields are already bound by reference when matching on `&self`.
+        let args = bindings.iter().map(|binding| if container.clone_on_extract { quote! { #binding } } else { quote! { &#binding } });
+
+        quote! {
+            #[must_use]
+            pub fn #fn_name(#receiver, #predicate) -> #returned_ty {
+                match self {
+                    #pattern if predicate(#(#args),*) => #returned,
+                    _ => #mismatch
+                }
+            }
+        }
+    }
+}
+
+/// `expect_{variant_name}_mut(&mut self, &fields...)`, like `expect_{variant_name}` but takes the expected values by reference
+/// and returns mutable references to the fields.
+fn mut_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_mut", variant.method_name(container.prefix.as_deref().unwrap_or("expect")));
+    let pattern = variant.pattern(name);
+    let guard = guard(container, variant, true, true);
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+    let generics = guard.generics(Some(&lifetime));
+    let Guard { args, guard, prelude, bounds, .. } = guard;
+
+    let values = variant.fields.iter().map(|field| field.mut_value(&field.binding));
+    let fields_ty = variant.fields.iter().map(|field| field.mut_ty(&lifetime));
+    let returned_ty = quote! { (#(#fields_ty),*) };
+    let returned = quote! { (#(#values),*) };
+    let expected = format!("expected {}::{}", name, variant.ident);
+
+    if variant.is_panic {
+        // `self` can't be shown while it is borrowed for the returned references, so it is checked first.
+        let guarded = variant.fields.iter().enumerate().filter(|(_, field)| !field.ignore_eq).map(|(index, _)| index).collect::<Vec<_>>();
+        let check = variant.partial_pattern(name, &guarded);
+        let (found, value) = found(variant);
+        let panic = panic_call(container, variant, &format!("{}, found {}", expected, found), value);
+
+        quote! {
+            #[track_caller]
+            pub fn #fn_name #generics(&#lifetime mut self, #args) -> #returned_ty #bounds {
+                #prelude
+                if !matches!(&*self, #check #guard) {
+                    #panic;
+                }
+                match self {
+                    #pattern => #returned,
+                    _ => unreachable!()
+                }
+            }
+        }
+    } else {
+        let (returned_ty, returned, mismatch) = if container.anyhow {
+            (quote! { ::anyhow::Result<#returned_ty> }, quote! { Ok(#returned) }, quote! { Err(::anyhow::anyhow!(#expected)) })
+        } else {
+            (quote! { Option<#returned_ty> }, quote! { Some(#returned) }, quote! { None })
+        };
+
+        quote! {
+            #[must_use]
+            pub fn #fn_name #generics(&#lifetime mut self, #args) -> #returned_ty #bounds {
+                #prelude
+                match self {
+                    #pattern #guard => #returned,
+                    _ => #mismatch
+                }
+            }
+        }
+    }
+}
+
+/// the arguments and the match guard of the methods comparing the fields to the arguments.
+struct Guard {
+    /// the arguments of the method, `a: T1, b: T2`.
+    args: TokenStream,
+    /// the guard of the match arm, empty if there is nothing to compare.
+    guard: TokenStream,
+    /// the statements to run before the match, binding the arguments for `tuple_args`.
+    prelude: TokenStream,
+    /// the where clause of the method, requiring the compared fields to implement PartialEq,
+    /// so the generic parameters of the enum don't need to be bounded.
+    bounds: TokenStream,
+    /// the generic parameters of the method with `eq_impl`, one for each compared argument, in the order of the arguments
+    /// so they can be given with a turbofish.
+    params: Vec<TokenStream>,
+}
+
+impl Guard {
+    /// the generic parameters of the method, after `lifetime` if any.
+    fn generics(&self, lifetime: Option<&syn::Lifetime>) -> TokenStream {
+        let params = lifetime.map(ToTokens::to_token_stream).into_iter().chain(self.params.iter().cloned()).collect::<Vec<_>>();
+        if params.is_empty() {
+            quote![]
+        } else {
+            quote! { <#(#params),*> }
+        }
+    }
+}
+
+/// `by_ref` is true if the fields are bound by reference, when matching on `&self`,
+/// and `eq_ref` if the expected values are taken by reference.
+fn guard(container: &ContainerAttrs, variant: &Variant, by_ref: bool, eq_ref: bool) -> Guard {
+    let args = variant.guarded_fields().map(|field| &field.arg).collect::<Vec<_>>();
+    let mut params = Vec::new();
+    let args_ty = variant.guarded_fields().map(|field| {
+        let ty = &field.ty;
+        // a field compared within a tolerance is subtracted from its argument, which needs the same type.
+        let ty = if container.eq_impl && field.epsilon.is_none() {
+            let param = format_ident!("__Eq{}", params.len());
+            params.push(quote! { #param: ::core::cmp::PartialEq<#ty> });
+            quote! { #param }
+        } else {
+            quote! { #ty }
+        };
+        let ty = if eq_ref {
+            quote! { &#ty }
+        } else {
+            ty
+        };
+        if container.optional_guard {
+            quote! { Option<#ty> }
+        } else {
+            ty
+        }
+    }).collect::<Vec<_>>();
+
+    // with `eq_trait`, the method defaults to the name of the trait in snake case, `approx_eq` for `ApproxEq`.
+    let eq_trait = container.eq_trait.as_ref().map(|path| {
+        let method = container.eq_method.clone().unwrap_or_else(|| {
+            let ident = &path.segments.last().expect("a path has at least one segment").ident;
+            format_ident!("{}", snake_case(&ident.unraw().to_string()), span = ident.span())
+        });
+        (path, method)
+    });
+
+    let comparisons = variant.guarded_fields().map(|field| {
+        let binding = &field.binding;
+        let ty = &field.ty;
+        let arg = &field.arg;
+        let binding = if by_ref { quote! { *#binding } } else { quote! { #binding } };
+        // with `optional_guard`, the argument is shadowed by a reference to the value in its `Some`.
+        let arg = match (container.optional_guard, eq_ref) {
+            (false, false) => quote! { #arg },
+            (false, true) | (true, false) => quote! { *#arg },
+            (true, true) => quote! { **#arg },
+        };
+        // with `eq_impl`, only the arguments implement PartialEq with the fields.
+        let comparison = if let Some(epsilon) = &field.epsilon {
+            quote! { (#binding - #arg).abs() <= #epsilon }
+        } else if container.eq_impl {
+            quote! { #arg == #binding }
+        } else if let Some((path, method)) = &eq_trait {
+            quote! { <#ty as #path>::#method(&#binding, &#arg) }
+        } else {
+            quote! { #binding == #arg }
+        };
+        if container.optional_guard {
+            let expected = &field.arg;
+            quote! {
+                (match &#expected {
+                    Some(#expected) => #comparison,
+                    None => true,
+                })
+            }
+        } else {
+            comparison
+        }
+    }).collect::<Vec<_>>();
+
+    let (guard, bounds) = if comparisons.is_empty() {
+        (quote![], quote![])
+    } else if container.eq_impl {
+        (quote! { if #(#comparisons)&&* }, quote![])
+    } else if let Some((path, _)) = &eq_trait {
+        let fields_ty = variant.guarded_fields().map(|field| &field.ty);
+        (quote! { if #(#comparisons)&&* }, quote! { where #(for<'__eq> #fields_ty: #path),* })
+    } else {
+        // the higher-ranked bound is only checked where the method is used, even for the fields that don't depend on the generics.
+        let fields_ty = variant.guarded_fields().map(|field| &field.ty);
+        (quote! { if #(#comparisons)&&* }, quote! { where #(for<'__eq> #fields_ty: ::core::cmp::PartialEq),* })
+    };
+
+    let (args, prelude) = if container.tuple_args && !args.is_empty() {
+        (quote! { expected: (#(#args_ty),*) }, quote! { let (#(#args),*) = expected; })
+    } else {
+        (quote! { #(#args: #args_ty),* }, quote![])
+    };
+
+    Guard {
+        args,
+        guard,
+        prelude,
+        bounds,
+        params,
+    }
+}
+
+/// the receiver of the methods returning the fields by value: `self`, or `&self` with `clone_on_extract`.
+fn extract_receiver(container: &ContainerAttrs) -> TokenStream {
+    if container.clone_on_extract {
+        quote! { &self }
+    } else {
+        quote! { self }
+    }
+}
+
+/// the values of the fields of `variant` returned by the methods taking the `extract_receiver`.
+fn extracted(container: &ContainerAttrs, variant: &Variant) -> Vec<TokenStream> {
+    extracted_fields(container, &variant.fields)
+}
+
+/// the values of `fields` bound by the pattern of their variant: the bindings, or their clones with `clone_on_extract`.
+fn extracted_fields<'a>(container: &ContainerAttrs, fields: impl IntoIterator<Item = &'a Field>) -> Vec<TokenStream> {
+    fields.into_iter().map(|field| {
+        let binding = &field.binding;
+        if container.clone_on_extract {
+            quote! { ::core::clone::Clone::clone(#binding) }
+        } else {
+            quote! { #binding }
+        }
+    }).collect()
+}
+
+/// the format string of a generated panic, `message` after the `panic_message_prefix`, if any.
+fn panic_message(container: &ContainerAttrs, message: &str) -> String {
+    match &container.panic_message_prefix {
+        Some(prefix) => format!("{} {}", prefix.replace('{', "{{").replace('}', "}}"), message),
+        None => message.to_string(),
+    }
+}
+
+/// the format of the value found instead of the variant in a panic message, and the value: `self` with its Debug,
+/// or only the name of its variant with `#[no_debug]`.
+fn found(variant: &Variant) -> (&'static str, TokenStream) {
+    if variant.no_debug {
+        ("{}", quote! { self.__expect_variant_name() })
+    } else {
+        ("{:?}", quote! { self })
+    }
+}
+
+/// the `panic!` of a `#[panic]` variant, with `message` formatting `args`.
+/// with `#[panic(with_location)]`, the location of the caller is added to the message.
+fn panic_call(container: &ContainerAttrs, variant: &Variant, message: &str, args: TokenStream) -> TokenStream {
+    let message = panic_message(container, message);
+    if variant.panic_location {
+        let message = format!("{} at {{}}", message);
+        quote! { panic!(#message, #args, ::core::panic::Location::caller()) }
+    } else {
+        quote! { panic!(#message, #args) }
+    }
+}
+
+fn guarded_method(name: &Ident, container: &ContainerAttrs, variant: &Variant, fn_name: &Ident, cloned: bool) -> TokenStream {
+    let pattern = variant.pattern(name);
+    let guard = guard(container, variant, cloned, container.eq_ref);
+    let generics = guard.generics(None);
+    let Guard { args, guard, prelude, bounds, .. } = guard;
+    let returned_ty = variant.returned_ty();
+    let receiver = if cloned { quote! { &self } } else { quote! { self } };
+
+    let values = variant.fields.iter().map(|field| {
+        let binding = &field.binding;
+        if cloned {
+            quote! { ::core::clone::Clone::clone(#binding) }
+        } else {
+            quote! { #binding }
+        }
+    });
+    let returned = variant.returned(values);
+
+    // with `debug_trace`, an arm only compiled in debug builds notes that the variant matched but not the values.
+    let trace = |mismatch: &TokenStream| if container.debug_trace && !guard.is_empty() {
+        let wildcard = variant.wildcard_pattern(name);
+        let note = format!("{}: the value is {}::{}, but its fields differ from the expected values", fn_name, name, variant.ident);
+        quote! {
+            #[cfg(debug_assertions)]
+            #wildcard => {
+                ::std::eprintln!(#note);
+                #mismatch
+            },
+        }
+    } else {
+        quote![]
+    };
+
+    if variant.is_panic {
+        // the expected value can only be shown if every field is given as argument, by value and with its type.
+        let panic = if container.eq_ref || container.eq_impl || container.optional_guard || variant.attrs.no_construct || variant.no_debug || variant.fields.iter().any(|field| field.ignore_eq) {
+            let (found, value) = found(variant);
+            let expected = format!("expected {}::{}, found {}", name, variant.ident, found);
+            panic_call(container, variant, &expected, value)
+        } else {
+            let expected = variant.construct();
+            panic_call(container, variant, "expected {:?}, found {:?}", quote! { #expected, self })
+        };
+
+        quote! {
+            #[track_caller]
+            pub fn #fn_name #generics(#receiver, #args) -> #returned_ty #bounds {
+                #prelude
+                match self {
+                    #pattern #guard => #returned,
+                    _ => #panic
+                }
+            }
+        }
+    } else if container.anyhow {
+        let expected = format!("expected {}::{}", name, variant.ident);
+        let trace = trace(&quote! { Err(::anyhow::anyhow!(#expected)) });
+
+        quote! {
+            #[must_use]
+            pub fn #fn_name #generics(#receiver, #args) -> ::anyhow::Result<#returned_ty> #bounds {
+                #prelude
+                match self {
+                    #pattern #guard => Ok(#returned),
+                    #trace
+                    _ => Err(::anyhow::anyhow!(#expected))
+                }
+            }
+        }
+    } else {
+        let trace = trace(&quote! { None });
+
+        quote! {
+            #[must_use]
+            pub fn #fn_name #generics(#receiver, #args) -> Option<#returned_ty> #bounds {
+                #prelude
+                match self {
+                    #pattern #guard => Some(#returned),
+                    #trace
+                    _ => None
+                }
+            }
+        }
+    }
+}
+
+/// `expect_{variant_name}_detailed(self, fields...)`, like `expect_{variant_name}` but tells whether the values or the variant
+/// didn't match, with a `{enum_name}MatchResult`.
+fn detailed_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_detailed", variant.method_name(container.prefix.as_deref().unwrap_or("expect")));
+    let result = format_ident!("{}MatchResult", name);
+    let pattern = variant.pattern(name);
+    let guard = guard(container, variant, container.clone_on_extract, container.eq_ref);
+    let generics = guard.generics(None);
+    let Guard { args, guard, prelude, bounds, .. } = guard;
+    let returned_ty = variant.returned_ty();
+    let receiver = extract_receiver(container);
+    let returned = variant.returned(extracted(container, variant));
+
+    // without anything to compare, the values can't differ.
+    let value_mismatch = if guard.is_empty() {
+        quote![]
+    } else {
+        quote! { #pattern => #result::ValueMismatch(#returned), }
+    };
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name #generics(#receiver, #args) -> #result<#returned_ty> #bounds {
+            #prelude
+            match self {
+                #pattern #guard => #result::Match(#returned),
+                #value_mismatch
+                _ => #result::VariantMismatch
+            }
+        }
+    }
+}
+
+/// `expect_{variant_name}_or_self(self, fields...)`, like `expect_{variant_name}` but gives `self` back in `Err` if it is
+/// another variant or its fields differ from the expected values.
+fn or_self_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_or_self", variant.method_name(container.prefix.as_deref().unwrap_or("expect")));
+    let pattern = variant.pattern(name);
+    let guard = guard(container, variant, false, container.eq_ref);
+    let generics = guard.generics(None);
+    let Guard { args, guard, prelude, bounds, .. } = guard;
+    let returned_ty = variant.returned_ty();
+    let returned = variant.returned(variant.fields.iter().map(|field| &field.binding));
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name #generics(self, #args) -> ::core::result::Result<#returned_ty, Self> #bounds {
+            #prelude
+            match self {
+                #pattern #guard => Ok(#returned),
+                other => Err(other)
+            }
+        }
+    }
+}
+
+/// `{enum_name}MatchResult<T>`, returned by the `expect_{variant_name}_detailed` methods.
+fn match_result_item(derive_input: &DeriveInput) -> TokenStream {
+    let DeriveInput { vis, ident: name, .. } = derive_input;
+    let result = format_ident!("{}MatchResult", name);
+    let doc = format!("The result of the `expect_{{variant_name}}_detailed` methods of [`{}`].", name);
+
+    quote! {
+        #[doc = #doc]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+        #vis enum #result<T> {
+            /// the value is the variant, and its fields are equal to the expected values.
+            Match(T),
+            /// the value is the variant, but its fields differ from the expected values.
+            ValueMismatch(T),
+            /// the value is another variant.
+            VariantMismatch,
+        }
+    }
+}
+
+/// `expect_{variant_name}_partial(self)`, returns the `#[extract(...)]` fields if `self` is the variant, ignoring the others.
+fn partial_method(name: &Ident, container: &ContainerAttrs, variant: &Variant, indices: &[usize]) -> TokenStream {
+    let fn_name = variant.method_name(container.prefix.as_deref().unwrap_or("expect"));
+    let fn_name = format_ident!("{}_partial", fn_name);
+    let pattern = variant.partial_pattern(name, indices);
+
+    let receiver = extract_receiver(container);
+    let fields_names = extracted_fields(container, indices.iter().map(|&index| &variant.fields[index]));
+    let fields_ty = indices.iter().map(|&index| &variant.fields[index].ty);
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name(#receiver) -> Option<(#(#fields_ty),*)> {
+            match self {
+                #pattern => Some((#(#fields_names),*)),
+                _ => None
+            }
+        }
+    }
+}
+
+/// `{variant_name}(...) -> Self`, builds the variant from its fields.
+fn construct_method(variant: &Variant) -> syn::Result<TokenStream> {
+    if let Style::Unit = variant.style {
+        return Err(syn::Error::new_spanned(&variant.ident, "`construct` needs a variant with at least one field"));
+    }
+
+    let name = &variant.name;
+    // the path keywords can't be raw identifiers.
+    if ["crate", "self", "super", "Self"].contains(&name.as_str()) {
+        let message = format!("`{}` can't be the name of the constructor, name it with `#[rename = \"...\"]`", name);
+        return Err(syn::Error::new_spanned(&variant.ident, message));
+    }
+    // a lowercased variant name can be a keyword, like `Type`.
+    let fn_name = match syn::parse_str::<Ident>(name) {
+        Ok(_) => Ident::new(name, variant.ident.span()),
+        Err(_) => Ident::new_raw(name, variant.ident.span()),
+    };
+    let args = variant.fields.iter().map(|field| &field.arg);
+    let args_ty = variant.fields.iter().map(|field| &field.ty);
+    let new = variant.construct();
+
+    Ok(quote! {
+        pub fn #fn_name(#(#args: #args_ty),*) -> Self {
+            #new
+        }
+    })
+}
+
+/// `with_{variant_name}(mut self, ...) -> Self`, replaces `self` with the variant built from its fields, for chaining.
+fn with_method(variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name("with");
+    let args = variant.fields.iter().map(|field| &field.arg);
+    let args_ty = variant.fields.iter().map(|field| &field.ty);
+    let new = variant.construct();
+
+    quote! {
+        pub fn #fn_name(mut self, #(#args: #args_ty),*) -> Self {
+            self = #new;
+            self
+        }
+    }
+}
+
+/// the struct returned instead of a tuple by the methods of a `#[return_struct]` variant.
+fn return_struct_item(derive_input: &DeriveInput, variant: &Variant, return_struct: &ReturnStruct) -> TokenStream {
+    let DeriveInput { vis, ident: name, generics, .. } = derive_input;
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let enum_ty = quote! { #name #ty_generics };
+    let ReturnStruct { ident, derives, .. } = return_struct;
+    let doc = format!("the fields of `{}::{}`.", name, variant.ident);
+
+    let fields_ty = variant.fields.iter().map(|field| replace_self(field.ty.to_token_stream(), &enum_ty));
+    let body = match variant.style {
+        Style::Named => {
+            let members = variant.fields.iter().map(|field| &field.member);
+            quote! { #where_clause { #(pub #members: #fields_ty),* } }
+        },
+        _ => quote! { ( #(pub #fields_ty),* ) #where_clause; },
+    };
+
+    quote! {
+        #[doc = #doc]
+        #[derive(#(#derives),*)]
+        #vis struct #ident #generics #body
+    }
+}
+
+/// `as_{variant_name}(&self)`, returns references to the fields if `self` is the variant.
+fn as_ref_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name(container.ref_prefix.as_deref().unwrap_or("as"));
+    let pattern = variant.pattern(name);
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+
+    let values = variant.fields.iter().map(|field| field.ref_value(&field.binding));
+    let fields_ty = variant.fields.iter().map(|field| field.ref_ty(&lifetime));
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name<#lifetime>(&#lifetime self) -> Option<(#(#fields_ty),*)> {
+            match self {
+                #pattern => Some((#(#values),*)),
+                _ => None
+            }
+        }
+    }
+}
+
+/// `try_as_{variant_name}(&self)`, returns references to the fields if `self` is the variant,
+/// or an `{enum_name}VariantMismatch` naming the expected and the actual variants.
+fn try_as_method(name: &Ident, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name("try_as");
+    let mismatch = format_ident!("{}VariantMismatch", name);
+    let pattern = variant.pattern(name);
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+    let expected = variant.ident.to_string();
+
+    let values = variant.fields.iter().map(|field| field.ref_value(&field.binding));
+    let fields_ty = variant.fields.iter().map(|field| field.ref_ty(&lifetime));
+
+    quote! {
+        pub fn #fn_name<#lifetime>(&#lifetime self) -> ::core::result::Result<(#(#fields_ty),*), #mismatch> {
+            match self {
+                #pattern => Ok((#(#values),*)),
+                _ => Err(#mismatch {
+                    expected: #expected,
+                    found: self.__expect_variant_name(),
+                })
+            }
+        }
+    }
+}
+
+/// `{enum_name}VariantMismatch`, the error of the `try_as_{variant_name}` methods.
+fn variant_mismatch_item(derive_input: &DeriveInput) -> TokenStream {
+    let DeriveInput { vis, ident: name, .. } = derive_input;
+    let mismatch = format_ident!("{}VariantMismatch", name);
+    let doc = format!("The error of the `try_as_{{variant_name}}` methods of [`{}`], when it is another variant.", name);
+    let message = format!("expected {}::{{}}, found {}::{{}}", name, name);
+
+    quote! {
+        #[doc = #doc]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+        #vis struct #mismatch {
+            /// the name of the expected variant.
+            pub expected: &'static str,
+            /// the name of the actual variant.
+            pub found: &'static str,
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #mismatch {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, #message, self.expected, self.found)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::std::error::Error for #mismatch {}
+    }
+}
+
+/// `as_mut_{variant_name}(&mut self)`, returns mutable references to the fields if `self` is the variant.
+fn as_mut_method(name: &Ident, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name("as_mut");
+    let pattern = variant.pattern(name);
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+
+    let values = variant.fields.iter().map(|field| field.mut_value(&field.binding));
+    let fields_ty = variant.fields.iter().map(|field| field.mut_ty(&lifetime));
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name<#lifetime>(&#lifetime mut self) -> Option<(#(#fields_ty),*)> {
+            match self {
+                #pattern => Some((#(#values),*)),
+                _ => None
+            }
+        }
+    }
+}
+
+/// `as_{variant_name}_pin(self: Pin<&mut Self>)`, returns pinned mutable references to the fields if `self` is the variant.
+fn pin_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_pin", variant.method_name(container.ref_prefix.as_deref().unwrap_or("as")));
+    let pattern = variant.pattern(name);
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+
+    let bindings = variant.fields.iter().map(|field| &field.binding);
+    let fields_ty = variant.fields.iter().map(|field| &field.ty);
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name<#lifetime>(self: ::core::pin::Pin<&#lifetime mut Self>) -> Option<(#(::core::pin::Pin<&#lifetime mut #fields_ty>),*)> {
+            // SAFETY: the fields are structurally pinned: the enum can't implement `Drop` (checked by the derive), and is only
+            // `Unpin` if all its fields are (implemented by the derive), so pinning the enum pins its fields.
+            match unsafe { ::core::pin::Pin::get_unchecked_mut(self) } {
+                #pattern => Some((#(unsafe { ::core::pin::Pin::new_unchecked(#bindings) }),*)),
+                _ => None
+            }
+        }
+    }
+}
+
+/// checks that the enum doesn't implement `Drop`, which could move the fields projected by `as_{variant_name}_pin`:
+/// the impl conflicts with the blanket impl for the types implementing `Drop`.
+fn not_drop_item(derive_input: &DeriveInput) -> TokenStream {
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    quote! {
+        const _: () = {
+            trait __ExpectMustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: ::core::ops::Drop> __ExpectMustNotImplDrop for T {}
+            impl #impl_generics __ExpectMustNotImplDrop for #name #ty_generics #where_clause {}
+        };
+    }
+}
+/// `impl Unpin for Enum` only if all the fields are `Unpin`, the phantom ones included, so a manual impl conflicts with it, as the auto impl
+/// `impl Unpin for Enum` only if all the pinned fields are `Unpin`, so a manual impl conflicts with it, as the auto impl
+/// is replaced: an `Unpin` enum could be moved after projecting its `!Unpin` fields.
+fn unpin_item(derive_input: &DeriveInput, variants: &[Variant]) -> TokenStream {
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, _) = derive_input.generics.split_for_impl();
+    let mut generics = derive_input.generics.clone();
+    // the higher-ranked bound is only checked where the impl is used, the fields not depending on the generics included.
+    generics.make_where_clause().predicates.extend(variants.iter().flat_map(Variant::all_fields).map(|field| -> syn::WherePredicate {
+        let ty = &field.ty;
+        syn::parse_quote! { for<'__pin> #ty: ::core::marker::Unpin }
+    }));
+    let where_clause = &generics.where_clause;
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::marker::Unpin for #name #ty_generics #where_clause {}
+    }
+}
+
+/// `impl Deref for Enum` to the field of the only variant, for the newtype-like enums.
+fn deref_item(derive_input: &DeriveInput, variants: &[Variant]) -> syn::Result<TokenStream> {
+    let name = &derive_input.ident;
+    let variant = match variants {
+        [variant] if variant.fields.len() == 1 => variant,
+        _ => return Err(syn::Error::new_spanned(name, "`deref` needs an enum with a single variant with a single field")),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+    let pattern = variant.pattern(name);
+    let Field { binding, ty, .. } = &variant.fields[0];
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+            type Target = #ty;
+
+            fn deref(&self) -> &Self::Target {
+                match self {
+                    #pattern => #binding,
+                }
+            }
+        }
+    })
+}
+
+/// the expression building the variant left by `take_{variant_name}`: the `sentinel = "..."` variant with its fields
+/// set to `Default::default()`, or the first unit variant.
+fn sentinel(container: &ContainerAttrs, name: &Ident, variants: &[Variant]) -> syn::Result<TokenStream> {
+    let variant = match &container.sentinel {
+        Some(sentinel) => match variants.iter().find(|variant| variant.ident == *sentinel) {
+            Some(variant) => variant,
+            None => return Err(syn::Error::new_spanned(sentinel, format!("no variant `{}` in `{}`", sentinel, name))),
+        },
+        None => match variants.iter().find(|variant| matches!(variant.style, Style::Unit)) {
+            Some(variant) => variant,
+            None => return Err(syn::Error::new_spanned(name, "`take` needs a unit variant to leave in place of the taken one, or a `sentinel = \"...\"`")),
+        },
+    };
+
+    let default = quote! { ::core::default::Default::default() };
+    Ok(variant.with_values(&quote! { Self }, variant.all_fields().into_iter().map(|_| &default)))
+}
+
+/// `take_{variant_name}(&mut self)`, returns the fields if `self` is the variant, leaving the sentinel variant in its place.
+fn take_method(name: &Ident, variant: &Variant, sentinel: &TokenStream) -> TokenStream {
+    let fn_name = variant.method_name("take");
+    let pattern = variant.pattern(name);
+    let wildcard = variant.wildcard_pattern(name);
+    let returned = variant.returned(variant.fields.iter().map(|field| &field.binding));
+    let returned_ty = variant.returned_ty();
+
+    quote! {
+        pub fn #fn_name(&mut self) -> Option<#returned_ty> {
+            if !matches!(self, #wildcard) {
+                return None;
+            }
+            match ::core::mem::replace(self, #sentinel) {
+                #pattern => Some(#returned),
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+/// `is_{variant_name}(&self)`, returns whether `self` is the variant.
+fn is_method(name: &Ident, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name("is");
+    let pattern = variant.wildcard_pattern(name);
+
+    quote! {
+        pub fn #fn_name(&self) -> bool {
+            matches!(self, #pattern)
+        }
+    }
+}
+
+/// `into_{variant_name}(self)`, returns the fields if `self` is the variant.
+fn into_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name(container.into_prefix.as_deref().unwrap_or("into"));
+    let pattern = variant.pattern(name);
+
+    let receiver = extract_receiver(container);
+    let returned = variant.returned(extracted(container, variant));
+    let returned_ty = variant.returned_ty();
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name(#receiver) -> Option<#returned_ty> {
+            match self {
+                #pattern => Some(#returned),
+                _ => None
+            }
+        }
+    }
+}
+
+/// `get_{variant_name}_{field_name}(&self)` for each named field, returns a reference to the field if `self` is the variant.
+fn getter_methods<'a>(name: &'a Ident, variant: &'a Variant) -> impl Iterator<Item = TokenStream> + 'a {
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+
+    variant.fields.iter().filter_map(move |field| {
+        let member = field.member.as_ref()?;
+        let field_name = field.rename.as_ref().unwrap_or(member).unraw();
+        let fn_name = format_ident!("{}_{}", variant.method_name("get"), field_name);
+        let variant_name = &variant.ident;
+        let binding = &field.binding;
+        let ty = field.ref_ty(&lifetime);
+        let value = field.ref_value(binding);
+        let lifetime = &lifetime;
+
+        Some(quote! {
+            #[must_use]
+            pub fn #fn_name<#lifetime>(&#lifetime self) -> Option<#ty> {
+                match self {
+                    #name::#variant_name { #member: #binding, .. } => Some(#value),
+                    _ => None
+                }
+            }
+        })
+    })
+}
+
+/// `set_{variant_name}_{field_name}(&mut self, value)` for each named field, replaces the field if `self` is the variant
+/// and returns its previous value.
+fn setter_methods<'a>(name: &'a Ident, variant: &'a Variant) -> impl Iterator<Item = TokenStream> + 'a {
+    variant.fields.iter().filter_map(move |field| {
+        let member = field.member.as_ref()?;
+        let field_name = field.rename.as_ref().unwrap_or(member).unraw();
+        let fn_name = format_ident!("{}_{}", variant.method_name("set"), field_name);
+        let variant_name = &variant.ident;
+        let binding = &field.binding;
+        let ty = &field.ty;
+
+        Some(quote! {
+            pub fn #fn_name(&mut self, value: #ty) -> Option<#ty> {
+                match self {
+                    #name::#variant_name { #member: #binding, .. } => Some(::core::mem::replace(#binding, value)),
+                    _ => None
+                }
+            }
+        })
+    })
+}
+
+/// `{field_name}(&self)` for a `#[common_field(...)]`, returns a reference to the field of whichever variant has it.
+///
+/// the types of the fields are compared as written, like for `by_type`.
+fn common_field_method(name: &Ident, variants: &[Variant], field_name: &Ident) -> syn::Result<TokenStream> {
+    let holders = variants.iter().filter_map(|variant| {
+        let field = variant.fields.iter().find(|field| field.member.as_ref() == Some(field_name))?;
+        Some((variant, field))
+    }).collect::<Vec<_>>();
+
+    let (_, first) = match holders.first() {
+        Some(holder) => *holder,
+        None => return Err(syn::Error::new_spanned(field_name, format!("no variant of `{}` has a field `{}`", name, field_name))),
+    };
+    let ty_tokens = first.ty.to_token_stream().to_string();
+    if let Some((_, field)) = holders.iter().find(|(_, field)| field.ty.to_token_stream().to_string() != ty_tokens) {
+        let message = format!("the `{}` fields of the variants need to have the same type, `{}`", field_name, ty_tokens);
+        return Err(syn::Error::new_spanned(&field.ty, message));
+    }
+
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+    let ty = first.ref_ty(&lifetime);
+    let arms = holders.iter().map(|(variant, field)| {
+        let variant_name = &variant.ident;
+        let binding = &field.binding;
+        let value = field.ref_value(binding);
+        quote! { #name::#variant_name { #field_name: #binding, .. } => Some(#value), }
+    });
+
+    Ok(quote! {
+        #[must_use]
+        pub fn #field_name<#lifetime>(&#lifetime self) -> Option<#ty> {
+            match self {
+                #(#arms)*
+                _ => None
+            }
+        }
+    })
+}
+
+/// `first_{type_name}(self)` for each type of the single-field variants, returns the field
+/// if `self` is one of the variants holding this type.
+///
+/// the types are compared as written, and only the types written as a single identifier are used.
+fn by_type_methods(name: &Ident, container: &ContainerAttrs, variants: &[Variant]) -> Vec<TokenStream> {
+    // the types in the order of their first variant, with the variants holding them.
+    let mut types: Vec<(&Ident, Vec<&Variant>)> = Vec::new();
+
+    for variant in variants {
+        let ty = match variant.fields.as_slice() {
+            [field] => match &field.ty {
+                Type::Path(path) if path.qself.is_none() => match path.path.get_ident() {
+                    Some(ty) if ty != "Self" => ty,
+                    _ => continue,
+                },
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        match types.iter_mut().find(|(other, _)| *other == ty) {
+            Some((_, holders)) => holders.push(variant),
+            None => types.push((ty, vec![variant])),
+        }
+    }
+
+    types.into_iter().map(|(ty, holders)| {
+        let fn_name = format_ident!("first_{}", snake_case(&ty.unraw().to_string()), span = ty.span());
+        let patterns = holders.iter().map(|variant| variant.pattern(name));
+        let receiver = extract_receiver(container);
+        let bindings = holders.iter().flat_map(|variant| extracted_fields(container, &variant.fields[..1]));
+
+        quote! {
+            #[must_use]
+            pub fn #fn_name(#receiver) -> Option<#ty> {
+                match self {
+                    #(#patterns => Some(#bindings),)*
+                    _ => None
+                }
+            }
+        }
+    }).collect()
+}
+
+/// `HashMap` to `hash_map`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// `iter_{variant_name}(&self)` for each `#[iter]` field, returns an iterator over the field by reference if `self` is the variant.
+/// the name of the field is added to the name of the method if the variant has more than one field.
+///
+/// with `owned`, `into_iter_{variant_name}(self)` instead, consuming `self` and returning an iterator over the owned items.
+fn iter_methods<'a>(name: &'a Ident, variant: &'a Variant, owned: bool) -> impl Iterator<Item = TokenStream> + 'a {
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+
+    variant.fields.iter().enumerate().filter(|(_, field)| field.iter).map(move |(index, field)| {
+        let fn_name = variant.method_name(if owned { "into_iter" } else { "iter" });
+        let fn_name = if variant.fields.len() == 1 {
+            fn_name
+        } else {
+            match &field.member {
+                Some(member) => format_ident!("{}_{}", fn_name, field.rename.as_ref().unwrap_or(member).unraw()),
+                None => format_ident!("{}_{}", fn_name, index),
+            }
+        };
+        let pattern = variant.partial_pattern(name, &[index]);
+        let binding = &field.binding;
+        let ty = &field.ty;
+        let lifetime = &lifetime;
+
+        if owned {
+            quote! {
+                #[must_use]
+                pub fn #fn_name(self) -> Option<<#ty as ::core::iter::IntoIterator>::IntoIter> {
+                    match self {
+                        #pattern => Some(::core::iter::IntoIterator::into_iter(#binding)),
+                        _ => None
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #[must_use]
+                pub fn #fn_name<#lifetime>(&#lifetime self) -> Option<<&#lifetime #ty as ::core::iter::IntoIterator>::IntoIter> {
+                    match self {
+                        #pattern => Some(::core::iter::IntoIterator::into_iter(#binding)),
+                        _ => None
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// `into_{variant_name}_future(self)`, returns the field as a future if `self` is the variant.
+fn future_method(name: &Ident, variant: &Variant) -> TokenStream {
+    let fn_name = format_ident!("{}_future", variant.method_name("into"));
+    let pattern = variant.pattern(name);
+    let field = &variant.fields[0];
+    let binding = &field.binding;
+    let ty = &field.ty;
+
+    // the higher-ranked bound is only checked where the method is used, so the fields which aren't futures are fine.
+    quote! {
+        #[must_use]
+        pub fn #fn_name(self) -> Option<impl ::core::future::Future<Output = <#ty as ::core::future::Future>::Output>>
+        where
+            for<'__future> #ty: ::core::future::Future,
+        {
+            match self {
+                #pattern => Some(#binding),
+                _ => None
+            }
+        }
+    }
+}
+
+/// `unwrap_{variant_name}(self)`, returns the fields, panics if `self` is not the variant.
+fn unwrap_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name("unwrap");
+    let pattern = variant.pattern(name);
+    let (found, value) = found(variant);
+    let expected = panic_message(container, &format!("expected {}::{}, found {}", name, variant.ident, found));
+
+    let receiver = extract_receiver(container);
+    let returned = variant.returned(extracted(container, variant));
+    let returned_ty = variant.returned_ty();
+
+    quote! {
+        #[track_caller]
+        pub fn #fn_name(#receiver) -> #returned_ty {
+            match self {
+                #pattern => #returned,
+                _ => panic!(#expected, #value)
+            }
+        }
+    }
+}
+
+/// `try_get_{variant_name}(self)`, returns the fields if `self` is the variant,
+/// or the name of the actual variant.
+fn try_get_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name("try_get");
+    let pattern = variant.pattern(name);
+
+    let receiver = extract_receiver(container);
+    let returned = variant.returned(extracted(container, variant));
+    let returned_ty = variant.returned_ty();
+
+    quote! {
+        #[must_use]
+        pub fn #fn_name(#receiver) -> ::core::result::Result<#returned_ty, &'static str> {
+            match self {
+                #pattern => Ok(#returned),
+                _ => Err(self.__expect_variant_name())
+            }
+        }
+    }
+}
+
+/// `into_{variant_name}_vec(self)`, returns the fields collected into a `Vec` if `self` is the variant.
+fn collect_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> syn::Result<TokenStream> {
+    let ty = match variant.fields.first() {
+        Some(field) => &field.ty,
+        None => return Err(syn::Error::new_spanned(&variant.ident, "`#[collect]` needs a variant with at least one field")),
+    };
+
+    let ty_tokens = ty.to_token_stream().to_string();
+    if let Some(field) = variant.fields.iter().find(|field| field.ty.to_token_stream().to_string() != ty_tokens) {
+        return Err(syn::Error::new_spanned(&field.ty, "`#[collect]` needs all the fields of the variant to have the same type"));
+    }
+
+    let fn_name = variant.method_name("into");
+    let fn_name = Ident::new(&format!("{}_vec", fn_name), fn_name.span());
+    let pattern = variant.pattern(name);
+    let receiver = extract_receiver(container);
+    let fields_names = extracted(container, variant);
+
+    Ok(quote! {
+        #[must_use]
+        pub fn #fn_name(#receiver) -> Option<Vec<#ty>> {
+            match self {
+                #pattern => Some(vec![#(#fields_names),*]),
+                _ => None
+            }
+        }
+    })
+}
+
+/// `assert_{variant_name}(&self)`, panics if `self` is not the variant.
+fn assert_method(name: &Ident, container: &ContainerAttrs, variant: &Variant) -> TokenStream {
+    let fn_name = variant.method_name("assert");
+    let pattern = variant.wildcard_pattern(name);
+    let expected = format!("assertion failed: expected `{}::{}`, found `{}::{{}}`", name, variant.ident, name);
+    let expected = panic_message(container, &expected);
+
+    quote! {
+        #[track_caller]
+        pub fn #fn_name(&self) {
+            if !matches!(self, #pattern) {
+                panic!(#expected, self.__expect_variant_name());
+            }
+        }
+    }
+}
+
+/// `discriminant_index(&self) -> usize`, the position of the current variant in the declaration order.
+fn discriminant_index_method(name: &Ident, variants: &[Variant]) -> TokenStream {
+    let patterns = variants.iter().map(|variant| variant.wildcard_pattern(name));
+    let indices = 0..variants.len();
+
+    quote! {
+        pub fn discriminant_index(&self) -> usize {
+            match *self {
+                #(#patterns => #indices,)*
+            }
+        }
+    }
+}
+
+/// `is_{group}(&self)` for each `#[expect_macro(group = "...")]`, returns whether `self` is one of the variants of the group.
+/// the groups are in the order they first appear in, so the methods are generated in a stable order.
+fn group_methods(name: &Ident, variants: &[Variant]) -> Vec<TokenStream> {
+    let mut groups: Vec<&Ident> = Vec::new();
+    for group in variants.iter().flat_map(|variant| &variant.attrs.groups) {
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+
+    groups.into_iter().map(|group| {
+        let fn_name = format_ident!("is_{}", group);
+        let patterns = variants.iter().filter(|variant| variant.attrs.groups.contains(group)).map(|variant| variant.wildcard_pattern(name));
+
+        quote! {
+            pub fn #fn_name(&self) -> bool {
+                matches!(self, #(#patterns)|*)
+            }
+        }
+    }).collect()
+}
+
+/// `into_result(self) -> Result<{fields}, Self>`, `Ok` with the fields of `ok_variant`, and `Err` with `self` for the
+/// other variants.
+fn into_result_method(name: &Ident, variants: &[Variant], ok_variant: &Ident) -> syn::Result<TokenStream> {
+    let variant = match variants.iter().find(|variant| variant.ident == *ok_variant) {
+        Some(variant) => variant,
+        None => return Err(syn::Error::new_spanned(ok_variant, format!("no variant `{}` in `{}`", ok_variant, name))),
+    };
+    let pattern = variant.pattern(name);
+    let returned = variant.returned(variant.fields.iter().map(|field| &field.binding));
+    let returned_ty = variant.returned_ty();
+
+    Ok(quote! {
+        #[must_use]
+        pub fn into_result(self) -> ::core::result::Result<#returned_ty, Self> {
+            match self {
+                #pattern => ::core::result::Result::Ok(#returned),
+                other => ::core::result::Result::Err(other)
+            }
+        }
+    })
+}
+
+/// `payload_type_name(&self) -> Option<&'static str>`, the type of the first field of the current variant, as written.
+fn payload_type_name_method(name: &Ident, variants: &[Variant]) -> TokenStream {
+    let patterns = variants.iter().map(|variant| variant.wildcard_pattern(name));
+    let type_names = variants.iter().map(|variant| match variant.fields.first() {
+        Some(field) => {
+            let ty = &field.ty;
+            quote! { Some(stringify!(#ty)) }
+        },
+        None => quote! { None },
+    });
+
+    quote! {
+        pub fn payload_type_name(&self) -> Option<&'static str> {
+            match *self {
+                #(#patterns => #type_names,)*
+            }
+        }
+    }
+}
+
+/// `code(&self) -> {repr}`, the discriminant of the current variant, as the integer type given with `#[repr(...)]`.
+/// the variants without an explicit discriminant follow the previous one, like the compiler does.
+fn repr_code_method(derive_input: &DeriveInput, variants: &[Variant]) -> syn::Result<TokenStream> {
+    const INTEGERS: [&str; 12] = ["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+    let name = &derive_input.ident;
+    let mut repr = None;
+    for attr in derive_input.attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident().filter(|ident| INTEGERS.contains(&ident.to_string().as_str())) {
+                repr = Some(ident.clone());
+            }
+            Ok(())
+        })?;
+    }
+    let repr = repr.ok_or_else(|| syn::Error::new_spanned(name, "`repr_code` requires an integer `#[repr(...)]`, e.g. `#[repr(u8)]`"))?;
+
+    let patterns = variants.iter().map(|variant| variant.wildcard_pattern(name));
+    // the last explicit discriminant, and how many variants came after it.
+    let mut base = None;
+    let mut offset = 0usize;
+    let codes = variants.iter().enumerate().map(|(i, variant)| {
+        if let Some(discriminant) = &variant.discriminant {
+            base = Some(discriminant);
+            offset = 0;
+        } else if i > 0 {
+            offset += 1;
+        }
+        let literal = proc_macro2::Literal::usize_unsuffixed(offset);
+        match base {
+            Some(base) if offset == 0 => quote! { #base },
+            Some(base) => quote! { (#base) + #literal },
+            None => quote! { #literal },
+        }
+    }).collect::<Vec<_>>();
+
+    Ok(quote! {
+        pub fn code(&self) -> #repr {
+            match *self {
+                #(#patterns => #codes,)*
+            }
+        }
+    })
+}
+
+/// `variant_rank(&self) -> usize`, the declaration index of the current variant as a stable ranking,
+/// and `by_variant(a, b) -> Ordering` comparing the ranks, e.g. for `sort_by`.
+fn variant_rank_methods(name: &Ident, variants: &[Variant]) -> Vec<TokenStream> {
+    let patterns = variants.iter().map(|variant| variant.wildcard_pattern(name));
+    let ranks = 0..variants.len();
+
+    vec![
+        quote! {
+            pub fn variant_rank(&self) -> usize {
+                match *self {
+                    #(#patterns => #ranks,)*
+                }
+            }
+        },
+        quote! {
+            pub fn by_variant(a: &Self, b: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&a.variant_rank(), &b.variant_rank())
+            }
+        },
+    ]
+}
+
+/// a private helper returning the name of the current variant, used in panic messages.
+fn variant_name_method(name: &Ident, variants: &[Variant]) -> TokenStream {
+    let patterns = variants.iter().map(|variant| variant.wildcard_pattern(name));
+    let names = variants.iter().map(|variant| variant.ident.to_string());
+
+    quote! {
+        fn __expect_variant_name(&self) -> &'static str {
+            match *self {
+                #(#patterns => #names,)*
+            }
+        }
+    }
+}
+
+/// errors if two `#[return_struct]` variants generate structs with the same name, or one has the name of the enum
+/// or of another item generated by the derive.
+fn check_struct_collisions(container: &ContainerAttrs, name: &Ident, variants: &[Variant]) -> syn::Result<()> {
+    let mut seen = HashSet::new();
+
+    // the items generated next to the enum, with the option generating them.
+    let mut generated = Vec::new();
+    for (enabled, suffix, option) in [
+        (container.ref_enum, ref_enum::REF_ENUM.suffix, ref_enum::REF_ENUM.option),
+        (container.projection, ref_enum::PROJECTION.suffix, ref_enum::PROJECTION.option),
+        (container.detailed, "MatchResult", "detailed"),
+        (container.try_as, "VariantMismatch", "try_as"),
+        (container.module.is_some(), "Ext", "module"),
+    ] {
+        if enabled {
+            generated.push((format!("{}{}", name, suffix), option));
+        }
+    }
+    if let Some(module) = &container.module {
+        generated.push((module.to_string(), "module"));
+    }
+
+    for return_struct in variants.iter().filter_map(|variant| variant.return_struct.as_ref()) {
+        let ident = &return_struct.ident;
+        if ident == name {
+            return Err(syn::Error::new(ident.span(), format!("the struct `{}` would have the name of the enum", ident)));
+        }
+        if let Some((_, option)) = generated.iter().find(|(generated, _)| ident == generated) {
+            return Err(syn::Error::new(ident.span(), format!("the struct `{}` would have the name of the item generated by `{}`", ident, option)));
+        }
+        if !seen.insert(ident.to_string()) {
+            return Err(syn::Error::new(ident.span(), format!("the struct `{}` is generated more than once", ident)));
+        }
+    }
+
+    Ok(())
+}
+
+/// reports the methods generated more than once, as rustc would only point at the derive.
+///
+/// the errors are reported in the order the methods are generated, so in the order of the variants.
+fn check_collisions(methods: &[TokenStream]) -> syn::Result<()> {
+    let mut seen = HashSet::new();
+    let mut errors: Option<syn::Error> = None;
+
+    for method in methods {
+        let method = syn::parse2::<ImplItemFn>(method.clone())?;
+        let fn_name = method.sig.ident;
+
+        if !seen.insert(fn_name.to_string()) {
+            let error = syn::Error::new(fn_name.span(), format!("the method `{}` is generated more than once", fn_name));
+            match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            }
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,72 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{DeriveInput, FnArg, GenericParam, Ident, ImplItemFn, Pat, Signature, Type};
+
+use crate::expand::replace_self;
+
+/// `#[expect_macro(impl_on = "...")]`: an inherent impl of the wrapper type with a method forwarding to each method of the enum.
+/// the methods taking `self` by reference rely on the deref coercion of the wrapper into the enum,
+/// and the methods taking `self` by value convert it with `Into`.
+pub(crate) fn forward_impl(derive_input: &DeriveInput, wrapper: &Type, methods: &[TokenStream]) -> syn::Result<TokenStream> {
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+    let enum_ty = quote! { #name #ty_generics };
+
+    // the impl takes the generics of the enum, which are unconstrained if the wrapper doesn't use them.
+    for param in &derive_input.generics.params {
+        let ident = match param {
+            GenericParam::Type(param) => &param.ident,
+            GenericParam::Lifetime(param) => &param.lifetime.ident,
+            GenericParam::Const(param) => &param.ident,
+        };
+        if !uses_ident(wrapper.to_token_stream(), ident) {
+            let message = format!("`impl_on` needs a wrapper using the generic parameters of the enum, `{}` isn't used by `{}`", ident, quote!(#wrapper));
+            return Err(syn::Error::new_spanned(wrapper, message));
+        }
+    }
+
+    let forwarding = methods.iter().map(|method| {
+        let method = syn::parse2::<ImplItemFn>(method.clone())?;
+        // `Self` is the wrapper in its impl, the signature keeps meaning the enum.
+        let sig = syn::parse2::<Signature>(replace_self(method.sig.to_token_stream(), &enum_ty))?;
+        let attrs = &method.attrs;
+        let vis = &method.vis;
+        let fn_name = &sig.ident;
+
+        let receiver = match sig.receiver() {
+            Some(receiver) if receiver.reference.is_some() => quote! { self, },
+            Some(_) => quote! { ::core::convert::Into::<#enum_ty>::into(self), },
+            None => quote![],
+        };
+        let args = sig.inputs.iter().filter_map(|arg| match arg {
+            FnArg::Typed(arg) => Some(&arg.pat),
+            FnArg::Receiver(_) => None,
+        }).map(|pat| match &**pat {
+            Pat::Ident(pat) => Ok(&pat.ident),
+            pat => Err(syn::Error::new_spanned(pat, "expected an identifier")),
+        }).collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #(#attrs)*
+            #vis #sig {
+                <#enum_ty>::#fn_name(#receiver #(#args),*)
+            }
+        })
+    }).collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #wrapper #where_clause {
+            #(#forwarding)*
+        }
+    })
+}
+
+/// whether `ident` is in the tokens, in a nested group too.
+fn uses_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token) => token == *ident,
+        TokenTree::Group(group) => uses_ident(group.stream(), ident),
+        _ => false,
+    })
+}
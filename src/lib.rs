@@ -1,9 +1,13 @@
-#![allow(unused_assignments)]
-
 use proc_macro::TokenStream;
 
-use syn::{parse_macro_input, DeriveInput, Data};
-use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attr;
+mod expand;
+mod impl_on;
+mod module;
+mod ref_enum;
+mod variant;
 
 ///
 /// # Usage
@@ -20,7 +24,7 @@ use quote::quote;
 /// # Example
 ///
 /// ```rust
-/// use expect_macro::Expect;
+/// use expect_macro_derive::Expect;
 ///
 /// #[derive(Debug, Expect)]
 /// enum Foo {
@@ -42,164 +46,1593 @@ use quote::quote;
 /// }
 /// ```
 ///
+/// Note: the names of the variants are lowercased, so a method can't be generated if two variants only differ by case:
+/// an error is reported for every method generated more than once, in the order of the variants.
+///
+/// Note: the arguments and the returned values are in the order the fields are declared,
+/// so reordering the fields of a variant changes the signature of its methods. see `#[stable_order]`.
+///
+/// Note: the methods taking `self` by value (`expect_{variant_name}`, `into_{variant_name}`, ...) consume it,
+/// unless the enum implements Copy: the value is then copied, and stays usable after the call.
+///
+/// Note: behind a smart pointer, the methods taking `&self` or `&mut self` are called through auto-deref, like
+/// `boxed.as_bar()` on a `Box<Foo>`. the methods taking `self` need the enum to be moved out first: `(*boxed).into_bar()`
+/// for a `Box`, or `Rc::try_unwrap(rc)` for an `Rc`. `#[expect_macro(clone_on_extract)]` or `cloned_accessors` make them
+/// take `&self` instead, so they are called through auto-deref too.
+///
+/// Note: the methods returning the fields without panicking are `#[must_use]`. whether the type of a field is `#[must_use]`
+/// can't be known by the derive, and ignoring the result of such a method is almost always a mistake anyway. the panicking
+/// methods (`#[panic]` variants, `unwrap_{variant_name}`) aren't, since they can be used as assertions.
+///
+/// Note: the compared fields need to implement PartialEq. it is required by `expect_{variant_name}` only,
+/// so the generic parameters of the enum don't need to be bounded for the other methods. a field that can't be compared,
+/// like a `Box<dyn FnMut()>`, can still be extracted with `into_{variant_name}`, `as_mut_{variant_name}` or
+/// `expect_{variant_name}_if`, or with `expect_{variant_name}` if the field is `#[ignore_eq]`.
+///
 /// # Attributes
 ///
+/// the attributes can be given in any order, and mixed with other attributes like `#[cfg]` or `#[doc]`.
+///
 /// ## `#[panic]`
 ///
 /// if this attribute is present on a variant, the generated method will panic instead of returning None.
+/// the panic is reported at the location of the call to the method, with a message showing the expected value
+/// built from the arguments and the actual value: `expected Bar { a: 1, b: 2 }, found Baz(1, 2)`.
+/// if some fields are not compared (`#[ignore_eq]`, `eq_ref`), only the name of the expected variant is shown.
+/// it is also the case for the `#[non_exhaustive]` variants and the variants with `#[expect_macro(no_construct)]`,
+/// so the variant doesn't need to be built in the method.
+///
+/// with `#[panic(with_location)]`, the location of the call is also written in the message, like
+/// `expected Bar(1), found Qux at src/main.rs:10:5`, for the environments where the location of the panic isn't shown.
 ///
 /// Note: the enum need to implement Debug.
-
-#[proc_macro_derive(Expect, attributes(panic))]
-pub fn expect_derive(input: TokenStream) -> TokenStream {
-    let derive_input = parse_macro_input!(input as DeriveInput);
-
-    let name = derive_input.ident;
-    let mut methods = Vec::new();
-
-
-    match derive_input.data {
-        Data::Enum(e) => {
-
-            for variant in e.variants {
-                let variant_name = variant.ident;
-
-                let is_panic = variant.attrs.iter().any(|attr| {
-                    attr.path().is_ident("panic")
-                });
-
-                let fn_name = format!("expect_{}", variant_name.to_string().to_lowercase());
-                let fn_name = syn::Ident::new(&fn_name, name.span());
-
-                let mut fields = vec![quote![]];
-                let mut fields_names = vec![quote![]];
-                let mut fields_ty= vec![quote![]];
-                let mut pattern = quote![];
-                let mut new = quote![];
-
-                match variant.fields {
-                    syn::Fields::Named(named) => {
-                        fields = named.named.iter().map(|field| {
-                            let name = field.ident.as_ref().expect("Expected field name");
-                            let ty = &field.ty;
-                            quote! {
-                                #name: #ty
-                            }
-                        }).collect::<Vec<_>>();
-
-                        let args = named.named.iter().map(|field| {
-                            let name = field.ident.as_ref().expect("Expected field name");
-                            quote! {
-                                #name
-                            }
-                        }).collect::<Vec<_>>();
-
-                        fields_names = named.named.iter().map(|field| {
-                            let name = field.ident.as_ref().expect("Expected field name 1");
-                            let name = syn::Ident::new(&name.to_string().to_lowercase(), name.span());
-                            let name = syn::Ident::new(&format!("attr_{}", name), name.span());
-                            quote! {
-                                #name
-                            }
-                        }).collect::<Vec<_>>();
-
-                        fields_ty = named.named.iter().map(|field| {
-                            let ty = &field.ty;
-                            quote! {
-                                #ty
-                            }
-                        }).collect::<Vec<_>>();
-
-                        pattern = quote! {
-                            #name::#variant_name { #(#args: #fields_names),* } if #(#fields_names == #args)&&*
-                        };
-
-                        new = quote! {
-                            #name::#variant_name { #(#args),* }
-                        };
-
-                    },
-                    syn::Fields::Unnamed(unnamed) => {
-                        let mut n = 0;
-                        let mut names = Vec::new();
-                        fields = unnamed.unnamed.iter().map(|field| {
-                            let ty = &field.ty;
-                            let name = syn::Ident::new(&format!("value_{}", n), name.span());
-                            names.push(name.clone());
-                            n += 1;
-                            quote! {
-                                #name: #ty
-                            }
-                        }).collect::<Vec<_>>();
-                        n = 0;
-                        fields_names = unnamed.unnamed.iter().map(|_| {
-                            let name = syn::Ident::new(&format!("attr_{}", n), name.span());
-                            n += 1;
-                            quote! {
-                                #name
-                            }
-                        }).collect::<Vec<_>>();
-
-                        fields_ty = unnamed.unnamed.iter().map(|field| {
-                            let ty = &field.ty;
-                            quote! {
-                                #ty
-                            }
-                        }).collect::<Vec<_>>();
-
-                        pattern = quote! {
-                            #name::#variant_name( #(#fields_names),* ) if #(#fields_names == #names)&&*
-                        };
-
-                        new = quote! {
-                            #name::#variant_name( #(#names),* )
-                        };
-
-                    },
-                    syn::Fields::Unit => {
-                        pattern = quote! {
-                            #name::#variant_name
-                        };
-
-                        new = quote! {
-                            #name::#variant_name
-                        };
-                    }
-                }
-                if is_panic {
-                    let method = quote! {
-                                pub fn #fn_name(self, #(#fields),*) -> (#(#fields_ty),*) {
-                                    match self {
-                                        #pattern => (#(#fields_names),*),
-                                        _ => panic!("Expected {:?} but got {:?}", #new, self)
-                                    }
-                                }
-                            };
-                    methods.push(method);
-                    continue;
-                } else {
-                    let method = quote! {
-                                pub fn #fn_name(self, #(#fields),*) -> Option<(#(#fields_ty),*)> {
-                                    match self {
-                                        #pattern => Some((#(#fields_names),*)),
-                                        _ => None
-                                    }
-                                }
-                            };
-                    methods.push(method);
-                }
-
-            }
-
-        },
-        _ => panic!("Expect can only be derived for enums")
-    }
-    let tokens = quote! {
-        impl #name {
-            #(#methods)*
-        }
-    };
-
-    tokens.into()
-}
-
+///
+/// ## `#[no_debug]`
+///
+/// if this attribute is present on a variant, the panic messages of its methods (`#[panic]`, `unwrap_{variant_name}`)
+/// only show the name of the variant found instead of its Debug output, like `expected Foo::Bar, found Qux`.
+/// the enum doesn't need to implement Debug if all the panicking variants are `#[no_debug]`, e.g. because a variant
+/// holds a payload which doesn't implement Debug; or it can implement it by hand for the other variants.
+///
+/// ```rust,should_panic
+/// use expect_macro_derive::Expect;
+///
+/// struct Handle;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     #[panic]
+///     #[no_debug]
+///     Bar(#[ignore_eq] Handle),
+///     Qux,
+/// }
+///
+/// // panics with "expected Foo::Bar, found Qux"
+/// Foo::Qux.expect_bar();
+/// ```
+///
+/// ## `#[expect_macro(panic_message_prefix = "...")]`
+///
+/// if this attribute is present on the enum, the prefix and a space are written before the messages of all the generated
+/// panics: the `#[panic]` variants, `unwrap_{variant_name}` and `assert_{variant_name}`.
+///
+/// ```rust,should_panic
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, Expect)]
+/// #[expect_macro(panic_message_prefix = "[MyApp]")]
+/// enum Foo {
+///     #[panic]
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// // panics with "[MyApp] expected Bar(1), found Qux"
+/// Foo::Qux.expect_bar(1);
+/// ```
+///
+/// ## `#[ignore_eq]`
+///
+/// if this attribute is present on a field, the field is still returned by `expect_{variant_name}`
+/// but is not compared, and the method doesn't take an argument for it.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     Bar { a: i32, #[ignore_eq] b: i32 },
+/// }
+///
+/// assert_eq!(Foo::Bar { a: 1, b: 2 }.expect_bar(1), Some((1, 2)));
+/// ```
+///
+/// ## `#[guard(epsilon = ...)]`
+///
+/// if this attribute is present on a float field (`f32` or `f64`), the field is compared to its argument within the given
+/// tolerance, `(field - expected).abs() <= epsilon`, instead of with `==`. it only works for the float-like types, with an
+/// `abs` method and a subtraction giving the same type.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     Bar(#[guard(epsilon = 0.001)] f64),
+/// }
+///
+/// assert_eq!(Foo::Bar(0.1 + 0.2).expect_bar(0.3), Some(0.1 + 0.2));
+/// assert_eq!(Foo::Bar(0.1).expect_bar(0.3), None);
+/// ```
+///
+/// ## `#[phantom]`
+///
+/// if this attribute is present on a field, or if the field is a `PhantomData<...>`, the field is left out of the
+/// generated methods: it is not compared, not returned, and the methods don't take an argument for it. when the
+/// variant is built (e.g. for the panic messages), the field is set to `Default::default()`, so it needs to implement
+/// Default, like `PhantomData` does.
+///
+/// ```rust
+/// use std::marker::PhantomData;
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo<T> {
+///     Bar(i32, PhantomData<T>),
+///     Baz { a: i32, #[phantom] b: () },
+/// }
+///
+/// assert_eq!(Foo::<u8>::Bar(1, PhantomData).expect_bar(1), Some(1));
+/// assert_eq!(Foo::<u8>::Baz { a: 1, b: () }.expect_baz(1), Some(1));
+/// ```
+///
+/// ## `#[collect]`
+///
+/// if this attribute is present on a variant whose fields all have the same type,
+/// an `into_{variant_name}_vec(self) -> Option<Vec<T>>` method is generated, collecting the fields into a `Vec`.
+///
+/// Note: the types are compared as written, `i32` and `std::primitive::i32` are different types for the macro.
+///
+/// ## `#[stable_order]`
+///
+/// if this attribute is present on a variant with named fields, its fields are taken in the order of their names
+/// instead of their declaration order, for the arguments and the returned values of all the generated methods.
+/// reordering the fields in the source then doesn't change the methods.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     #[stable_order]
+///     Bar { b: &'static str, a: i32 },
+/// }
+///
+/// assert_eq!(Foo::Bar { b: "b", a: 1 }.expect_bar(1, "b"), Some((1, "b")));
+/// ```
+///
+/// ## `#[return_struct]`
+///
+/// if this attribute is present on a variant, the methods returning its fields by value (`expect_{variant_name}`,
+/// `into_{variant_name}`, `unwrap_{variant_name}`, ...) return them in a `{enum_name}{variant_name}` struct
+/// generated next to the enum, instead of a tuple. the struct has the fields of the variant, and the traits given with
+/// `#[return_struct(derive(...))]`, which allows to compare it in tests. `#[return_struct(name = "...")]` gives the struct
+/// another name, e.g. when `{enum_name}{variant_name}` is already taken. an error is reported if a struct has the name of
+/// the enum, of another struct, or of a type generated by the other options, like `{enum_name}Ref` with `ref_enum`.
+///
+/// Note: the generics of the enum are the generics of the struct, so they need to be used by the fields of the variant.
+///
+/// Note: the std traits are only implemented for the tuples of up to 12 fields, so the tuple returned for a variant with more
+/// fields couldn't be compared or printed, e.g. with `assert_eq!`. such a variant is an error without `#[return_struct]`,
+/// and `#[return_struct(derive(Debug, PartialEq))]` makes the returned value comparable.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(into)]
+/// enum Event {
+///     #[return_struct(derive(Debug, PartialEq))]
+///     Click { x: i32, y: i32 },
+///     #[return_struct(name = "Size", derive(Debug, PartialEq))]
+///     Resize(u32, u32),
+///     Quit,
+/// }
+///
+/// assert_eq!(Event::Click { x: 1, y: 2 }.into_click().unwrap(), EventClick { x: 1, y: 2 });
+/// assert_eq!(Event::Resize(3, 4).into_resize().unwrap(), Size(3, 4));
+/// ```
+///
+/// ## `#[box_return]`
+///
+/// if this attribute is present on a variant, the methods returning its fields by value return them in a `Box`,
+/// `Option<Box<(T1, T2)>>`, so a large payload is moved to the heap instead of being copied in the returned value.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(into)]
+/// enum Foo {
+///     #[box_return]
+///     Bar([u8; 4096], u32),
+///     Qux,
+/// }
+///
+/// let bar: Box<([u8; 4096], u32)> = Foo::Bar([0; 4096], 1).into_bar().unwrap();
+/// assert_eq!(bar.1, 1);
+/// ```
+///
+/// ## `#[iter]`
+///
+/// if this attribute is present on a field, an `iter_{variant_name}(&self)` method is generated, returning an iterator
+/// over the field by reference if `self` is the variant, and None otherwise. if the variant has more than one field,
+/// the method is named `iter_{variant_name}_{field_name}` (or `iter_{variant_name}_{index}`).
+/// on a variant with a single field, the attribute can be put on the variant.
+///
+/// Note: a reference to the field needs to implement IntoIterator, like `&Vec<T>` or `&HashMap<K, V>`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     #[iter]
+///     Items(Vec<String>),
+///     Qux,
+/// }
+///
+/// let items = Foo::Items(vec!["a".to_string(), "b".to_string()]);
+/// assert_eq!(items.iter_items().unwrap().collect::<Vec<_>>(), ["a", "b"]);
+/// assert!(Foo::Qux.iter_items().is_none());
+/// ```
+///
+/// ## `#[expect_macro(into_iter)]`
+///
+/// if this attribute is present on the enum, an `into_iter_{variant_name}(self)` method is also generated for each `#[iter]`
+/// field, named like `iter_{variant_name}`, consuming `self` and returning an iterator over the owned items of the field
+/// if `self` is the variant. the field needs to implement IntoIterator, like `Vec<T>` or `HashSet<T>`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(into_iter)]
+/// enum Foo {
+///     #[iter]
+///     Items(Vec<String>),
+///     Qux,
+/// }
+///
+/// let items = Foo::Items(vec!["a".to_string(), "b".to_string()]);
+/// assert_eq!(items.into_iter_items().unwrap().collect::<Vec<String>>(), ["a", "b"]);
+/// ```
+///
+/// ## `#[expect_macro(future_accessors)]`
+///
+/// if this attribute is present on the enum, an `into_{variant_name}_future(self)` method is generated for each variant
+/// with a single field, returning the field as a future if `self` is the variant, so it can be awaited.
+///
+/// Note: the method can only be called if the field implements Future.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+/// use std::future::{ready, Ready};
+///
+/// #[derive(Expect)]
+/// #[expect_macro(future_accessors)]
+/// enum Task {
+///     Running(Ready<u32>),
+///     Count(u32),
+/// }
+///
+/// async fn output(task: Task) -> Option<u32> {
+///     Some(task.into_running_future()?.await)
+/// }
+/// ```
+///
+/// ## `#[expect_macro(construct)]`
+///
+/// if this attribute is present on a variant with fields, a `{variant_name}(...) -> Self` associated function is generated,
+/// taking the fields in order and building the variant. a keyword name is a raw identifier, `r#type` for `Type`, except
+/// `crate`, `self` and `super`, which need a `#[rename = "..."]`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(into)]
+/// enum Foo {
+///     #[expect_macro(construct)]
+///     Bar { a: i32, b: i32 },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::bar(1, 2).into_bar(), Some((1, 2)));
+/// ```
+///
+/// ## `#[expect_macro(with_methods)]`
+///
+/// if this attribute is present on the enum, a `with_{variant_name}(self, ...) -> Self` method is generated for each variant,
+/// taking the fields in order and replacing `self` with the variant, so the calls can be chained.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, PartialEq, Expect)]
+/// #[expect_macro(with_methods)]
+/// enum Foo {
+///     Bar { a: i32, b: i32 },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Qux.with_bar(1, 2), Foo::Bar { a: 1, b: 2 });
+/// assert_eq!(Foo::Qux.with_bar(1, 2).with_qux(), Foo::Qux);
+/// ```
+///
+/// ## `#[extract(...)]`
+///
+/// if this attribute is present on a variant with named fields, an `expect_{variant_name}_partial(self)` method is generated,
+/// returning only the listed fields, in the given order, if `self` is the variant, and None otherwise.
+/// the other fields are ignored, so their types don't need to implement anything.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     #[extract(url)]
+///     Request { url: String, method: String },
+///     Qux,
+/// }
+///
+/// let request = Foo::Request { url: "/".to_string(), method: "GET".to_string() };
+/// assert_eq!(request.expect_request_partial(), Some("/".to_string()));
+/// ```
+///
+/// ## `#[rename = "..."]`
+///
+/// if this attribute is present on a variant, the given name is used instead of the lowercased variant name
+/// in the names of its methods. the variant names that are not ASCII need to be renamed.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(into)]
+/// enum Foo {
+///     #[rename = "size"]
+///     Größe(u32),
+/// }
+///
+/// assert_eq!(Foo::Größe(1).into_size(), Some(1));
+/// ```
+///
+/// ## `#[expect_macro(rename_method = "...")]`
+///
+/// if this attribute is present on a variant, its `expect_{variant_name}` method gets the given name instead,
+/// without the prefix. the other methods of the variant (`into_{variant_name}`, `expect_{variant_name}_cloned`, ...) keep their names.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     #[expect_macro(rename_method = "payload")]
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar(1).payload(1), Some(1));
+/// ```
+///
+/// ## `#[expect_macro(prefix_by_arity)]`
+///
+/// if this attribute is present on the enum, the unit variants get an `is_{variant_name}(&self) -> bool` method instead of
+/// `expect_{variant_name}(self) -> Option<()>`, while the variants with fields keep `expect_{variant_name}`. a unit variant
+/// with `#[panic]` or `#[expect_macro(rename_method = "...")]` keeps its `expect` method, and `#[rename = "..."]` is used in
+/// the name of the `is` method like in the other names.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(prefix_by_arity)]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar(1).expect_bar(1), Some(1));
+/// assert!(Foo::Qux.is_qux());
+/// ```
+///
+/// ## `#[expect_macro(group = "...")]`
+///
+/// if this attribute is present on variants, an `is_{group}(&self) -> bool` method is generated for each group,
+/// returning true if `self` is any of the variants of the group. a variant can be in several groups, by repeating the option.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// enum Foo {
+///     #[expect_macro(group = "open")]
+///     Bar(i32),
+///     #[expect_macro(group = "open")]
+///     Baz { a: String },
+///     Qux,
+/// }
+///
+/// assert!(Foo::Bar(1).is_open());
+/// assert!(Foo::Baz { a: String::new() }.is_open());
+/// assert!(!Foo::Qux.is_open());
+/// ```
+///
+/// ## `#[expect_macro(assertions)]`
+///
+/// if this attribute is present on the enum, an `assert_{variant_name}(&self)` method is generated
+/// for each variant. it panics with the name of the actual variant if `self` is not the expected one,
+/// and is meant to be used in tests.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(assertions)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// Foo::Bar { a: 1 }.assert_bar();
+/// Foo::Qux.assert_qux();
+/// ```
+///
+/// ## `#[expect_macro(as_ref)]`
+///
+/// if this attribute is present on the enum, an `as_{variant_name}(&self)` method is generated for each variant,
+/// returning references to the fields if `self` is the variant, and None otherwise.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(as_ref)]
+/// enum Foo {
+///     Bar { a: i32, b: String },
+///     Qux,
+/// }
+///
+/// let bar = Foo::Bar { a: 1, b: "b".to_string() };
+/// assert_eq!(bar.as_bar(), Some((&1, &"b".to_string())));
+/// assert_eq!(bar.as_qux(), None);
+/// ```
+///
+/// ## `#[expect_macro(try_as)]`
+///
+/// if this attribute is present on the enum, a `try_as_{variant_name}(&self)` method is generated for each variant,
+/// returning references to the fields like `as_{variant_name}`, or a generated `{enum_name}VariantMismatch` error
+/// with the names of the `expected` and the `found` variants. the error implements Display and Error.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, Expect)]
+/// #[expect_macro(try_as)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar { a: 1 }.try_as_bar(), Ok(&1));
+/// let error = Foo::Qux.try_as_bar().unwrap_err();
+/// assert_eq!((error.expected, error.found), ("Bar", "Qux"));
+/// assert_eq!(error.to_string(), "expected Foo::Bar, found Foo::Qux");
+/// ```
+///
+/// ## `#[expect_macro(cloned_accessors)]`
+///
+/// if this attribute is present on the enum, an `expect_{variant_name}_cloned(&self, ...)` method is generated for each variant.
+/// it works like `expect_{variant_name}` but takes `self` by reference and returns clones of the fields.
+///
+/// Note: the fields need to implement Clone.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(cloned_accessors)]
+/// enum Foo {
+///     Bar(String),
+/// }
+///
+/// let bar = Foo::Bar("a".to_string());
+/// assert_eq!(bar.expect_bar_cloned("a".to_string()), Some("a".to_string()));
+/// assert_eq!(bar.expect_bar("a".to_string()), Some("a".to_string()));
+/// ```
+///
+/// ## `#[expect_macro(borrow_self)]`
+///
+/// if this attribute is present on the enum, `expect_{variant_name}` takes `&self` instead of `self`, and returns clones
+/// of the fields like `expect_{variant_name}_cloned`, so the value can still be used after the check.
+///
+/// Note: the fields need to implement Clone.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(borrow_self)]
+/// enum Foo {
+///     Bar(String),
+///     Qux,
+/// }
+///
+/// let bar = Foo::Bar("a".to_string());
+/// assert_eq!(bar.expect_bar("a".to_string()), Some("a".to_string()));
+/// assert_eq!(bar.expect_bar("b".to_string()), None);
+/// ```
+///
+/// ## `#[expect_macro(clone_on_extract)]`
+///
+/// if this attribute is present on the enum, every method returning the fields by value takes `&self` instead of `self`
+/// and returns clones of the fields: `expect_{variant_name}`, `into_{variant_name}`, `unwrap_{variant_name}`,
+/// `try_get_{variant_name}`, the `_or_default`, `_if`, `_detailed` and `_partial` forms, `into_{variant_name}_vec` and
+/// `first_{type_name}`. the methods returning `self` back, taking it apart or building a new value (`or_self`, `into_result`,
+/// `take`, `with`, `into_iter` and `future_accessors`) still consume it.
+///
+/// Note: the fields need to implement Clone.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, Expect)]
+/// #[expect_macro(clone_on_extract, into, unwrap)]
+/// enum Foo {
+///     Bar(String),
+///     Qux,
+/// }
+///
+/// let bar = Foo::Bar("a".to_string());
+/// assert_eq!(bar.into_bar(), Some("a".to_string()));
+/// assert_eq!(bar.unwrap_bar(), "a");
+/// assert_eq!(bar.expect_bar("a".to_string()), Some("a".to_string()));
+/// ```
+///
+/// ## `#[expect_macro(mut_accessors)]`
+///
+/// if this attribute is present on the enum, an `expect_{variant_name}_mut(&mut self, ...)` method is generated for each variant.
+/// it works like `expect_{variant_name}` but takes the expected values by reference and returns mutable references to the fields,
+/// so a variant can be modified only if its fields have some values.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(mut_accessors)]
+/// enum Foo {
+///     Bar { a: i32, b: i32 },
+/// }
+///
+/// let mut bar = Foo::Bar { a: 1, b: 2 };
+/// if let Some((_, b)) = bar.expect_bar_mut(&1, &2) {
+///     *b = 3;
+/// }
+/// assert_eq!(bar.expect_bar_mut(&1, &2), None);
+/// assert_eq!(bar.expect_bar(1, 3), Some((1, 3)));
+/// ```
+///
+/// ## `#[expect_macro(or_default)]`
+///
+/// if this attribute is present on the enum, an `expect_{variant_name}_or_default(self)` method is generated for each variant
+/// with fields, returning the fields if `self` is the variant, and their default values otherwise, like `Option::unwrap_or_default`.
+///
+/// Note: the fields need to implement Default.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(or_default)]
+/// enum Foo {
+///     Bar { a: i32, b: String },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar { a: 1, b: "b".to_string() }.expect_bar_or_default(), (1, "b".to_string()));
+/// assert_eq!(Foo::Qux.expect_bar_or_default(), (0, String::new()));
+/// ```
+///
+/// ## `#[expect_macro(eq_ref)]`
+///
+/// if this attribute is present on the enum, `expect_{variant_name}` takes the expected values by reference,
+/// so they are not moved into the method. the fields are only compared, and moved out of `self` if they match:
+/// nothing is cloned.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(eq_ref)]
+/// enum Foo {
+///     Bar(String),
+/// }
+///
+/// let expected = "a".to_string();
+/// assert_eq!(Foo::Bar("a".to_string()).expect_bar(&expected), Some(expected.clone()));
+/// ```
+///
+/// ## `#[expect_macro(eq_impl)]`
+///
+/// if this attribute is present on the enum, the guarded methods take any value comparable to the fields,
+/// `impl PartialEq<T>` for a field of type `T`, e.g. a `&str` for a `String` field.
+///
+/// the type of each compared argument is a generic parameter of the method, in the order of the arguments, so it can be
+/// given with a turbofish when it can't be inferred, e.g. for an argument built with `Default::default()` or `.into()`.
+/// the fields compared with an `epsilon` keep their own type and don't have a parameter.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(eq_impl)]
+/// enum Foo {
+///     Bar { name: String, age: u32 },
+/// }
+///
+/// let bar = Foo::Bar { name: "alice".to_string(), age: 30 };
+/// assert_eq!(bar.expect_bar::<&str, u32>("alice", 30u8.into()), Some(("alice".to_string(), 30)));
+/// ```
+///
+/// ## `#[expect_macro(eq_trait = "...")]`
+///
+/// if this attribute is present on the enum, the guarded methods compare the fields with a method of this trait instead
+/// of `==`, for a custom equality like an approximate comparison of floats. the method takes the field and the expected
+/// value by reference and returns a bool, `fn approx_eq(&self, other: &Self) -> bool`, and is named after the trait in
+/// snake case, `approx_eq` for `ApproxEq`, unless given with `eq_method = "..."`.
+///
+/// the compared fields must implement the trait. the fields compared with an `epsilon` still use the tolerance,
+/// and it can't be used with `eq_impl`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// trait ApproxEq {
+///     fn approx_eq(&self, other: &Self) -> bool;
+/// }
+///
+/// impl ApproxEq for f64 {
+///     fn approx_eq(&self, other: &Self) -> bool {
+///         (self - other).abs() < 1e-9
+///     }
+/// }
+///
+/// #[derive(Expect)]
+/// #[expect_macro(eq_trait = "ApproxEq")]
+/// enum Shape {
+///     Circle(f64),
+/// }
+///
+/// assert_eq!(Shape::Circle(0.1 + 0.2).expect_circle(0.3), Some(0.1 + 0.2));
+/// ```
+///
+/// ## `#[expect_macro(optional_guard)]`
+///
+/// if this attribute is present on the enum, the guarded methods take an `Option` for each compared field: `Some(value)`
+/// must be equal to the field, and `None` matches any value, to only check some of the fields at the call site.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(optional_guard)]
+/// enum Foo {
+///     Bar { a: i32, b: String },
+/// }
+///
+/// let bar = Foo::Bar { a: 1, b: "b".to_string() };
+/// assert_eq!(bar.expect_bar(Some(1), None), Some((1, "b".to_string())));
+/// ```
+///
+/// ## `#[expect_macro(detailed)]`
+///
+/// if this attribute is present on the enum, an `expect_{variant_name}_detailed(self, ...)` method is generated for each variant,
+/// comparing the fields like `expect_{variant_name}`, but telling the two failures apart with a generated
+/// `{enum_name}MatchResult<T>` enum: `Match(fields)`, `ValueMismatch(fields)` if `self` is the variant with other values,
+/// and `VariantMismatch` if `self` is another variant. it never panics, even for a `#[panic]` variant.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(detailed)]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar(1).expect_bar_detailed(1), FooMatchResult::Match(1));
+/// assert_eq!(Foo::Bar(1).expect_bar_detailed(2), FooMatchResult::ValueMismatch(1));
+/// assert_eq!(Foo::Qux.expect_bar_detailed(1), FooMatchResult::VariantMismatch);
+/// ```
+///
+/// ## `#[expect_macro(or_self)]`
+///
+/// if this attribute is present on the enum, an `expect_{variant_name}_or_self(self, ...)` method is generated for each variant,
+/// comparing the fields like `expect_{variant_name}` and returning them in `Ok`, or giving `self` back in `Err` to see what
+/// arrived instead, both if `self` is another variant and if its fields differ from the expected values.
+/// it never panics, even for a `#[panic]` variant.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, PartialEq, Expect)]
+/// #[expect_macro(or_self)]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar(1).expect_bar_or_self(1), Ok(1));
+/// assert_eq!(Foo::Bar(1).expect_bar_or_self(2), Err(Foo::Bar(1)));
+/// assert_eq!(Foo::Qux.expect_bar_or_self(1), Err(Foo::Qux));
+/// ```
+///
+/// ## `#[expect_macro(debug_trace)]`
+///
+/// if this attribute is present on the enum, the guarded methods returning an Option (or an `anyhow::Result`) print a note
+/// to stderr when `self` is the variant but the fields differ from the expected values, to help debugging a failing test.
+/// the note is only compiled with `debug_assertions`, so the release builds are unchanged.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(debug_trace)]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// // prints "expect_bar: the value is Foo::Bar, but its fields differ from the expected values" in debug builds.
+/// assert_eq!(Foo::Bar(1).expect_bar(2), None);
+/// ```
+///
+/// ## `#[expect_macro(tuple_args)]`
+///
+/// if this attribute is present on the enum, `expect_{variant_name}` takes the expected values as a single tuple,
+/// `expect_bar(self, expected: (T1, T2))`, instead of an argument per field.
+/// a variant with a single field still takes the value itself.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(tuple_args)]
+/// enum Foo {
+///     Bar { a: i32, b: i32 },
+/// }
+///
+/// let expected = (1, 2);
+/// assert_eq!(Foo::Bar { a: 1, b: 2 }.expect_bar(expected), Some((1, 2)));
+/// ```
+///
+/// ## `#[expect_macro(bound = "...")]`
+///
+/// if this attribute is present on the enum, the given predicates are added to the where clause of the generated impl,
+/// so the bounds needed by the generated methods don't have to be on the enum.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(cloned_accessors, bound = "T: Clone + PartialEq")]
+/// enum Foo<T> {
+///     Bar(T),
+/// }
+///
+/// assert_eq!(Foo::Bar(1).expect_bar_cloned(1), Some(1));
+/// ```
+///
+/// ## `#[expect_macro(module = "...")]`
+///
+/// if this attribute is present on the enum, the methods are not generated in an inherent impl of the enum,
+/// but in a `{enum_name}Ext` trait declared in a module with the given name, next to the enum.
+/// the trait needs to be imported to call the methods, which keeps them out of the way of the other methods of the enum.
+///
+/// the module imports everything from the module of the enum with `use super::*`,
+/// so the types of the fields are resolved the same way as in the enum.
+/// the module has the visibility of the enum, and the trait is visible everywhere the enum is:
+/// a `pub(super)` enum gets a `pub(in super::super)` trait, a private enum a `pub(super)` trait.
+///
+/// Note: this can't be used on an enum declared inside a function, the module wouldn't see it.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(module = "foo_ext")]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// use foo_ext::FooExt;
+///
+/// fn main() {
+///     assert_eq!(Foo::Bar(1).expect_bar(1), Some(1));
+/// }
+/// ```
+///
+/// ## `#[expect_macro(into)]`
+///
+/// if this attribute is present on the enum, an `into_{variant_name}(self)` method is generated for each variant,
+/// returning the fields if `self` is the variant, and None otherwise. unlike `expect_{variant_name}`, the fields are not compared.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(into)]
+/// enum Foo {
+///     Bar { a: i32, b: i32 },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar { a: 1, b: 2 }.into_bar(), Some((1, 2)));
+/// assert_eq!(Foo::Qux.into_bar(), None);
+/// ```
+///
+/// ## `#[expect_macro(as_mut)]`
+///
+/// if this attribute is present on the enum, an `as_mut_{variant_name}(&mut self)` method is generated for each variant,
+/// returning mutable references to the fields if `self` is the variant, and None otherwise.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(as_mut)]
+/// enum Foo {
+///     Bar { a: i32, b: i32 },
+///     Qux,
+/// }
+///
+/// let mut bar = Foo::Bar { a: 1, b: 2 };
+/// if let Some((a, _)) = bar.as_mut_bar() {
+///     *a = 3;
+/// }
+/// assert_eq!(bar.expect_bar(3, 2), Some((3, 2)));
+/// ```
+///
+/// ## `#[expect_macro(deref)]`
+///
+/// if this attribute is present on an enum with a single variant with a single field, `Deref` is implemented for the enum,
+/// targeting the field, so a newtype-like wrapper can be used like the value it wraps. the derive fails for the other enums.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(deref)]
+/// enum Name {
+///     Name(String),
+/// }
+///
+/// let name = Name::Name("Ferris".to_string());
+/// assert_eq!(name.len(), 6);
+/// ```
+///
+/// ## `#[expect_macro(pin_projections)]`
+///
+/// if this attribute is present on the enum, an `as_{variant_name}_pin(self: Pin<&mut Self>)` method is generated for each
+/// variant, returning pinned mutable references to the fields (`Option<(Pin<&mut T1>, Pin<&mut T2>, ...)>`) if `self`
+/// is the variant, and None otherwise. it is meant for the async state machines and the other `!Unpin` enums, where
+/// `as_mut_{variant_name}` can't be called.
+///
+/// the projection is unsafe code relying on the fields being structurally pinned, which needs the enum to:
+/// - not implement `Drop`, since `drop` takes `&mut self` and could move the fields. this is checked by the derive,
+///   with a conflicting impl error if the enum implements `Drop`.
+/// - not implement `Unpin` by hand. the derive implements it if all the fields are `Unpin`, so a manual impl is rejected
+///   with a conflicting impl error.
+/// - not move the fields out of a pinned enum in any other unsafe code, e.g. through `Pin::get_unchecked_mut`.
+///
+/// ```rust
+/// use std::marker::PhantomPinned;
+/// use std::pin::Pin;
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(pin_projections)]
+/// enum Foo {
+///     Bar(i32, PhantomPinned),
+///     Qux,
+/// }
+///
+/// let mut foo = Box::pin(Foo::Bar(1, PhantomPinned));
+/// let (a, _) = foo.as_mut().as_bar_pin().unwrap();
+/// *a.get_mut() += 1;
+/// assert_eq!(foo.as_mut().as_bar_pin().map(|(a, _)| *a), Some(2));
+/// ```
+///
+/// ## `#[expect_macro(take)]`, `#[expect_macro(sentinel = "...")]`
+///
+/// if this attribute is present on the enum, a `take_{variant_name}(&mut self)` method is generated for each variant,
+/// returning the fields if `self` is the variant and leaving a sentinel variant in its place, like `Option::take`.
+/// the sentinel is the first unit variant, or the variant given with `sentinel = "..."`, whose fields are then set
+/// to `Default::default()`. without a unit variant nor a `sentinel`, an error is reported.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, PartialEq, Expect)]
+/// #[expect_macro(take)]
+/// enum Foo {
+///     Bar(String),
+///     Qux,
+/// }
+///
+/// let mut foo = Foo::Bar("a".to_string());
+/// assert_eq!(foo.take_bar(), Some("a".to_string()));
+/// assert_eq!(foo, Foo::Qux);
+/// ```
+///
+/// ## `#[expect_macro(is)]`
+///
+/// if this attribute is present on the enum, an `is_{variant_name}(&self) -> bool` method is generated for each variant.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(is)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// assert!(Foo::Bar { a: 1 }.is_bar());
+/// assert!(!Foo::Qux.is_bar());
+/// ```
+///
+/// ## `#[expect_macro(contains)]`
+///
+/// if this attribute is present on the enum, a `contains_{variant_name}(&self, expected: &T) -> bool` method is generated
+/// for each variant with a single field (or a single `#[extract(...)]` field), returning true if `self` is the variant
+/// and the field is equal to `expected`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(contains)]
+/// enum Foo {
+///     Bar(i32),
+///     #[extract(a)]
+///     Baz { a: i32, b: String },
+///     Qux,
+/// }
+///
+/// assert!(Foo::Bar(1).contains_bar(&1));
+/// assert!(!Foo::Bar(1).contains_bar(&2));
+/// assert!(Foo::Baz { a: 1, b: "b".to_string() }.contains_baz(&1));
+/// ```
+///
+/// ## `#[expect_macro(predicate_accessors)]`
+///
+/// if this attribute is present on the enum, an `expect_{variant_name}_if(self, predicate)` method is generated for each
+/// variant with fields. instead of comparing the fields, they are given by reference to `predicate`, and the fields are
+/// returned if `self` is the variant and `predicate` returns true. like `expect_{variant_name}`, it returns an Option,
+/// or panics for a `#[panic]` variant.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(predicate_accessors)]
+/// enum Foo {
+///     Bar(i32, i32),
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar(1, 2).expect_bar_if(|a, b| *a > 0 && *b == 2), Some((1, 2)));
+/// assert_eq!(Foo::Bar(-1, 2).expect_bar_if(|a, b| *a > 0 && *b == 2), None);
+/// ```
+///
+/// ## `#[expect_macro(unwrap)]`
+///
+/// if this attribute is present on the enum, an `unwrap_{variant_name}(self)` method is generated for each variant,
+/// returning the fields if `self` is the variant, and panicking otherwise. the fields are not compared.
+///
+/// Note: the enum need to implement Debug.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, Expect)]
+/// #[expect_macro(unwrap)]
+/// enum Foo {
+///     Bar { a: i32, b: i32 },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar { a: 1, b: 2 }.unwrap_bar(), (1, 2));
+/// ```
+///
+/// ## `#[expect_macro(all)]`, `#[expect_macro(only(...))]`
+///
+/// `all` enables every accessor family: `assertions`, `as_ref`, `as_mut`, `is`, `contains`, `cloned_accessors`, `mut_accessors`, `predicate_accessors`, `or_default`, `into`, `unwrap`, `try_get`, `getters`, `setters`, `take`, `with_methods`, `or_self` and `try_as`.
+/// `take` is left out for an enum without a unit variant, unless a `sentinel = "..."` is given.
+/// `only(...)` enables the listed families, e.g. `#[expect_macro(only(is, as_ref))]`. without them, only the families given
+/// as options are generated, and `expect_{variant_name}` is always generated.
+///
+/// Note: with `all`, the enum need to implement Debug and the fields need to implement Clone.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, Expect)]
+/// #[expect_macro(all)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// let bar = Foo::Bar { a: 1 };
+/// assert!(bar.is_bar());
+/// assert_eq!(bar.as_bar(), Some(&1));
+/// assert_eq!(bar.get_bar_a(), Some(&1));
+/// assert_eq!(bar.unwrap_bar(), 1);
+/// ```
+///
+/// ## `#[expect_macro(serde_untagged_helper)]`
+///
+/// if this attribute is present on the enum, `as_{variant_name}` and `is_{variant_name}` are generated,
+/// like with `#[expect_macro(as_ref, is)]`, to find out which variant an untagged serde enum was deserialized into.
+/// the crate doesn't depend on serde.
+///
+/// ## `#[expect_macro(anyhow)]`
+///
+/// if this attribute is present on the enum, the `expect_{variant_name}` methods of the variants without `#[panic]`
+/// (and `expect_{variant_name}_cloned`) return an `anyhow::Result` instead of an Option, with an `expected Enum::Variant` error.
+///
+/// Note: the generated code uses `::anyhow`, so the crate using the derive needs to depend on `anyhow`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(anyhow)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// fn a(foo: Foo) -> anyhow::Result<i32> {
+///     let a = foo.expect_bar(1)?;
+///     Ok(a)
+/// }
+///
+/// assert_eq!(a(Foo::Bar { a: 1 }).unwrap(), 1);
+/// assert_eq!(a(Foo::Qux).unwrap_err().to_string(), "expected Foo::Bar");
+/// ```
+///
+/// ## `#[expect_macro(try_get)]`
+///
+/// if this attribute is present on the enum, a `try_get_{variant_name}(self)` method is generated for each variant,
+/// returning the fields if `self` is the variant, and the name of the actual variant otherwise.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(try_get)]
+/// enum Foo {
+///     Bar(i32),
+///     Baz,
+/// }
+///
+/// assert_eq!(Foo::Bar(1).try_get_bar(), Ok(1));
+/// assert_eq!(Foo::Baz.try_get_bar(), Err("Baz"));
+/// ```
+///
+/// ## `#[expect_macro(prefix = "...")]`
+///
+/// this attribute replaces the `expect` prefix of the `expect_{variant_name}` methods.
+/// the generated methods are inherent methods, so they can't have the name of a method already defined on the enum:
+/// changing the prefix (or using `#[expect_macro(module = "...")]`) avoids the conflict.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(prefix = "into")]
+/// enum Foo {
+///     Bar(i32),
+/// }
+///
+/// impl Foo {
+///     fn expect_bar(&self) -> bool {
+///         matches!(self, Foo::Bar(_))
+///     }
+/// }
+///
+/// assert!(Foo::Bar(1).expect_bar());
+/// assert_eq!(Foo::Bar(1).into_bar(1), Some(1));
+/// ```
+///
+/// ## `#[expect_macro(getters)]`
+///
+/// if this attribute is present on the enum, a `get_{variant_name}_{field_name}(&self)` method is generated
+/// for each field of the variants with named fields, returning a reference to the field if `self` is the variant.
+///
+/// `#[rename = "..."]` on a field replaces the name of the field in the name of its getter.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(getters)]
+/// enum Foo {
+///     Bar { a: i32, #[rename = "b"] very_long_name: String },
+///     Qux,
+/// }
+///
+/// let bar = Foo::Bar { a: 1, very_long_name: "b".to_string() };
+/// assert_eq!(bar.get_bar_a(), Some(&1));
+/// assert_eq!(bar.get_bar_b(), Some(&"b".to_string()));
+/// assert_eq!(Foo::Qux.get_bar_a(), None);
+/// ```
+///
+/// ## `#[expect_macro(setters)]`
+///
+/// if this attribute is present on the enum, a `set_{variant_name}_{field_name}(&mut self, value)` method is generated
+/// for each field of the variants with named fields. if `self` is the variant, the field is replaced with `value` and its
+/// previous value is returned, otherwise `self` is left as is and `None` is returned.
+///
+/// `#[rename = "..."]` on a field is used in the name of its setter too.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(setters)]
+/// enum Foo {
+///     Bar { a: i32, b: String },
+///     Qux,
+/// }
+///
+/// let mut bar = Foo::Bar { a: 1, b: "b".to_string() };
+/// assert_eq!(bar.set_bar_a(2), Some(1));
+/// assert_eq!(bar.set_bar_a(3), Some(2));
+/// assert_eq!(Foo::Qux.set_bar_a(1), None);
+/// ```
+///
+/// ## `#[common_field(...)]`
+///
+/// if this attribute is present on the enum, a `{field_name}(&self) -> Option<&T>` method is generated for each listed field,
+/// returning a reference to the field of whichever variant has it, and None for the other variants. the fields need to have
+/// the same type in every variant, compared as written.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[common_field(id)]
+/// enum Foo {
+///     Bar { id: u64, name: String },
+///     Baz { id: u64 },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar { id: 1, name: String::new() }.id(), Some(&1));
+/// assert_eq!(Foo::Baz { id: 2 }.id(), Some(&2));
+/// assert_eq!(Foo::Qux.id(), None);
+/// ```
+///
+/// ## `#[expect_macro(by_type)]`
+///
+/// if this attribute is present on the enum, a `first_{type_name}(self)` method is generated for each type
+/// held by the variants with a single field, returning the field of whichever of these variants `self` is.
+/// the name of the type is converted to snake case, `first_hash_map` for `HashMap`.
+///
+/// Note: the types are compared as written, and only the types written as a single identifier
+/// (`String`, not `std::string::String` or `Vec<u8>`) are used.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(by_type)]
+/// enum Foo {
+///     Name(String),
+///     Alias(String),
+///     Age(u32),
+/// }
+///
+/// assert_eq!(Foo::Alias("a".to_string()).first_string(), Some("a".to_string()));
+/// assert_eq!(Foo::Age(1).first_string(), None);
+/// assert_eq!(Foo::Age(1).first_u32(), Some(1));
+/// ```
+///
+/// ## `#[expect_macro(discriminant_eq)]`
+///
+/// if this attribute is present on the enum, an `eq_variant(&self, other: &Self) -> bool` method is generated,
+/// returning true if both values are the same variant, whatever their fields are.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(discriminant_eq)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// assert!(Foo::Bar { a: 1 }.eq_variant(&Foo::Bar { a: 2 }));
+/// assert!(!Foo::Bar { a: 1 }.eq_variant(&Foo::Qux));
+/// ```
+///
+/// ## `#[expect_macro(as_discriminant)]`
+///
+/// if this attribute is present on the enum, an `as_discriminant(&self) -> Discriminant<Self>` method is generated,
+/// returning the `core::mem::Discriminant` of the variant. it is cheap to compare and implements Hash,
+/// so the kinds of variants can be stored in sets or used as map keys.
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(as_discriminant)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// let kinds = [Foo::Bar { a: 1 }, Foo::Bar { a: 2 }, Foo::Qux].iter().map(Foo::as_discriminant).collect::<HashSet<_>>();
+/// assert_eq!(kinds.len(), 2);
+/// ```
+///
+/// ## `#[expect_macro(discriminant_index)]`
+///
+/// if this attribute is present on the enum, a `discriminant_index(&self) -> usize` method is generated,
+/// returning the position of the variant in the declaration order, starting at 0. unlike the discriminant,
+/// it doesn't depend on the explicit discriminants of the enum, and can be used to index arrays.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(discriminant_index)]
+/// enum Foo {
+///     Bar = 10,
+///     Qux = 20,
+/// }
+///
+/// assert_eq!(Foo::Bar.discriminant_index(), 0);
+/// assert_eq!(Foo::Qux.discriminant_index(), 1);
+/// ```
+///
+/// ## `#[expect_macro(result_ok_variant = "...")]`
+///
+/// this attribute designates the variant of a result-like enum which is the success, and generates an
+/// `into_result(self) -> Result<T, Self>` method, returning the fields of this variant in `Ok`
+/// (like `into_{variant_name}`), or `self` in `Err` for any other variant.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, PartialEq, Expect)]
+/// #[expect_macro(result_ok_variant = "Done")]
+/// enum Foo {
+///     Done(i32),
+///     Failed { reason: String },
+/// }
+///
+/// assert_eq!(Foo::Done(1).into_result(), Ok(1));
+/// assert_eq!(Foo::Failed { reason: "no".to_string() }.into_result(), Err(Foo::Failed { reason: "no".to_string() }));
+/// ```
+///
+/// ## `#[expect_macro(variant_rank)]`
+///
+/// if this attribute is present on the enum, a `variant_rank(&self) -> usize` method is generated, returning the position of
+/// the variant in the declaration order like `discriminant_index`, as a stable ranking of the variants. a
+/// `by_variant(a: &Self, b: &Self) -> Ordering` function compares the ranks, to sort the values by variant without `Ord`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Debug, PartialEq, Expect)]
+/// #[expect_macro(variant_rank)]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// let mut foos = vec![Foo::Qux, Foo::Bar(2), Foo::Bar(1)];
+/// foos.sort_by(Foo::by_variant);
+/// assert_eq!(foos, vec![Foo::Bar(2), Foo::Bar(1), Foo::Qux]);
+/// ```
+///
+/// ## `#[expect_macro(variant_count)]`
+///
+/// if this attribute is present on the enum, a `VARIANT_COUNT: usize` associated const is generated, the number of variants.
+/// it is always in the inherent impl, even with `module = "..."` or `impl_on = "..."`, and works for generic enums too.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(variant_count)]
+/// enum Foo<T> {
+///     Bar(T),
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::<i32>::VARIANT_COUNT, 2);
+/// let slots = [0; Foo::<String>::VARIANT_COUNT];
+/// ```
+///
+/// ## `#[expect_macro(variants_with_data)]`
+///
+/// if this attribute is present on the enum, a `variants_with_data() -> &'static [&'static str]` function is generated,
+/// returning the names of the variants with fields, in declaration order. the unit variants are left out, so it lists
+/// the variants the payload can be extracted from.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(variants_with_data)]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+///     Baz { a: String },
+/// }
+///
+/// assert_eq!(Foo::variants_with_data(), ["Bar", "Baz"]);
+/// ```
+///
+/// ## `#[expect_macro(assert_count = ...)]`
+///
+/// if this attribute is present on the enum, the derive fails if the enum doesn't have this number of variants, so adding
+/// or removing a variant points to the code which hardcodes the count, e.g. a table with an entry for each variant.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(assert_count = 2)]
+/// enum Foo {
+///     Bar(i32),
+///     Qux,
+/// }
+///
+/// const NAMES: [&str; 2] = ["bar", "qux"];
+/// ```
+///
+/// ## `#[expect_macro(payload_type_name)]`
+///
+/// if this attribute is present on the enum, a `payload_type_name(&self) -> Option<&'static str>` method is generated,
+/// returning the type of the first field of the variant as written in the enum (with `stringify!`), or None for a
+/// variant without fields. handy to debug heterogeneous enums.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(payload_type_name)]
+/// enum Foo {
+///     Bar(i32),
+///     Baz { a: String, b: bool },
+///     Qux,
+/// }
+///
+/// assert_eq!(Foo::Bar(1).payload_type_name(), Some("i32"));
+/// assert_eq!(Foo::Baz { a: String::new(), b: true }.payload_type_name(), Some("String"));
+/// assert_eq!(Foo::Qux.payload_type_name(), None);
+/// ```
+///
+/// ## `#[expect_macro(repr_code)]`
+///
+/// if this attribute is present on the enum, a `code(&self)` method is generated, returning the discriminant of the variant
+/// as the integer type of the `#[repr(...)]` of the enum, which is required. it works for enums with fields too, and the
+/// explicit and implicit discriminants can be mixed: like for the compiler, a variant without an explicit discriminant
+/// gets the previous discriminant plus one, or 0 for the first variant.
+///
+/// the codes are computed from the discriminants written in the enum and returned by a match, the value is never read
+/// through a pointer, so the layout of the enum doesn't matter and `#[repr(C, u8)]` works like `#[repr(u8)]`.
+/// the other methods don't depend on the layout either.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(repr_code)]
+/// #[repr(u8)]
+/// enum Foo {
+///     Bar(i32) = 10,
+///     Baz { a: String },
+///     Qux = 20,
+/// }
+///
+/// assert_eq!(Foo::Bar(1).code(), 10);
+/// assert_eq!(Foo::Baz { a: String::new() }.code(), 11);
+/// assert_eq!(Foo::Qux.code(), 20);
+/// ```
+///
+/// ## `#[expect_macro(into_prefix = "...", ref_prefix = "...")]`
+///
+/// these attributes replace the `into` prefix of the `into_{variant_name}` methods,
+/// and the `as` prefix of the `as_{variant_name}` methods.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(into, as_ref, into_prefix = "take", ref_prefix = "peek")]
+/// enum Foo {
+///     Bar(i32),
+/// }
+///
+/// assert_eq!(Foo::Bar(1).peek_bar(), Some(&1));
+/// assert_eq!(Foo::Bar(1).take_bar(), Some(1));
+/// ```
+///
+/// ## `#[expect_macro(hidden)]`
+///
+/// if this attribute is present on the enum, every generated method is marked `#[doc(hidden)]`,
+/// so they can be used without appearing in the documentation of the enum.
+///
+/// ## `#[as_dyn]`
+///
+/// if this attribute is present on a field, `as_{variant_name}` and the getters return a reference to
+/// the target of the field instead of the field itself: `&dyn Trait` for a `Box<dyn Trait>`, or `&str` for a `String`.
+///
+/// Note: the type of the field needs to implement Deref.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// trait Draw {
+///     fn draw(&self) -> String;
+/// }
+///
+/// struct Button;
+///
+/// impl Draw for Button {
+///     fn draw(&self) -> String {
+///         "button".to_string()
+///     }
+/// }
+///
+/// #[derive(Expect)]
+/// #[expect_macro(as_ref)]
+/// enum Widget {
+///     Custom(#[as_dyn] #[ignore_eq] Box<dyn Draw>),
+/// }
+///
+/// let widget = Widget::Custom(Box::new(Button));
+/// let custom: &dyn Draw = widget.as_custom().unwrap();
+/// assert_eq!(custom.draw(), "button");
+/// ```
+///
+/// ## `#[as_str]`
+///
+/// the same as `#[as_dyn]` for a `String` field, so `as_{variant_name}`, the getters and the other methods returning a
+/// reference to the field return a `&str`. the derive fails if the field isn't a `String`.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(as_ref, getters)]
+/// enum Foo {
+///     Bar { #[as_str] name: String, id: u32 },
+/// }
+///
+/// let bar = Foo::Bar { name: "a".to_string(), id: 1 };
+/// assert_eq!(bar.as_bar(), Some(("a", &1)));
+/// assert_eq!(bar.get_bar_name(), Some("a"));
+/// ```
+///
+/// ## `#[expect_macro(impl_on = "...")]`
+///
+/// if this attribute is present on the enum, the methods are also generated on the given wrapper type,
+/// forwarding to the methods of the enum. it allows calling them on a newtype around the enum.
+///
+/// Note: the wrapper needs to implement `Deref<Target = Enum>` (and `DerefMut` for the methods taking `&mut self`),
+/// and the enum needs to implement `From<Wrapper>` for the methods taking `self` by value.
+/// it can't be used with `module`, nor with `pin_projections` as the wrapper isn't pinned like the enum.
+/// for a generic enum, the wrapper must use its generic parameters, `impl_on = "Wrapper<T>"` for `Foo<T>`:
+/// a wrapper of a concrete `Foo<i32>` is an error.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(impl_on = "Wrapper", as_ref)]
+/// enum Foo {
+///     Bar { a: i32 },
+///     Qux,
+/// }
+///
+/// #[repr(transparent)]
+/// struct Wrapper(Foo);
+///
+/// impl std::ops::Deref for Wrapper {
+///     type Target = Foo;
+///
+///     fn deref(&self) -> &Foo {
+///         &self.0
+///     }
+/// }
+///
+/// impl From<Wrapper> for Foo {
+///     fn from(wrapper: Wrapper) -> Foo {
+///         wrapper.0
+///     }
+/// }
+///
+/// let wrapper = Wrapper(Foo::Bar { a: 1 });
+/// assert_eq!(wrapper.as_bar(), Some(&1));
+/// assert_eq!(wrapper.expect_bar(1), Some(1));
+/// ```
+///
+/// ## `#[expect_macro(ref_enum)]`
+///
+/// if this attribute is present on the enum, a `{enum_name}Ref` enum is generated, with the same variants
+/// but holding references to the fields, and an `as_ref_enum(&self)` method returns it.
+/// it allows matching on the fields of the enum without moving or cloning them.
+/// `to_owned(&self)` on the `{enum_name}Ref` clones the fields back into an owned `{enum_name}`, if they implement Clone.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(ref_enum)]
+/// enum Foo {
+///     Bar { a: String },
+///     Qux,
+/// }
+///
+/// let foo = Foo::Bar { a: "a".to_string() };
+/// match foo.as_ref_enum() {
+///     FooRef::Bar { a } => assert_eq!(a, "a"),
+///     FooRef::Qux => unreachable!(),
+/// }
+/// ```
+///
+/// ## `#[expect_macro(projection)]`
+///
+/// the same as `ref_enum`, with a `{enum_name}Projection` enum returned by a `project(&self)` method, for a single
+/// call matching exhaustively on borrowed fields instead of calling the `as_{variant_name}` methods one after the other.
+/// both can be enabled together.
+///
+/// ```rust
+/// use expect_macro_derive::Expect;
+///
+/// #[derive(Expect)]
+/// #[expect_macro(projection)]
+/// enum Foo {
+///     Bar { a: String },
+///     Qux(u8),
+/// }
+///
+/// let foo = Foo::Bar { a: "a".to_string() };
+/// let len = match foo.project() {
+///     FooProjection::Bar { a } => a.len(),
+///     FooProjection::Qux(n) => *n as usize,
+/// };
+/// ```
+#[proc_macro_derive(Expect, attributes(panic, no_debug, collect, ignore_eq, rename, as_dyn, as_str, stable_order, return_struct, box_return, iter, extract, phantom, guard, common_field, expect_macro))]
+pub fn expect_derive(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    expand::derive(derive_input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
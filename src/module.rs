@@ -0,0 +1,95 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, FnArg, ImplItemFn, Pat, PatWild, Signature, Visibility};
+
+/// `#[expect_macro(module = "...")]`: the methods are emitted in a `{enum_name}Ext` trait,
+/// implemented for the enum inside the generated module instead of an inherent impl.
+pub(crate) fn ext_module(derive_input: &DeriveInput, module: &syn::Ident, methods: &[TokenStream]) -> syn::Result<TokenStream> {
+    let name = &derive_input.ident;
+    let vis = &derive_input.vis;
+    let trait_vis = nested_visibility(vis);
+    let trait_name = format_ident!("{}Ext", name);
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    let mut declarations = Vec::new();
+    let mut implementations = Vec::new();
+
+    for method in methods {
+        let mut method = syn::parse2::<ImplItemFn>(method.clone())?;
+        method.vis = Visibility::Inherited;
+
+        // `#[must_use]` is only checked on the declaration, the attributes about the body stay on the implementation.
+        let (attrs, declared): (Vec<_>, Vec<_>) = method.attrs.into_iter().partition(|attr| attr.path().is_ident("track_caller") || attr.path().is_ident("inline"));
+        let sig = declaration(&method.sig);
+        declarations.push(quote! {
+            #(#declared)*
+            #sig;
+        });
+
+        method.attrs = attrs;
+        implementations.push(method);
+    }
+
+    let doc = format!("The methods generated by `Expect` for [`{}`].", name);
+    let module_doc = format!("The `{}` trait.", trait_name);
+
+    Ok(quote! {
+        #[doc = #module_doc]
+        #vis mod #module {
+            use super::*;
+
+            // `Sized` like the enum is in the inherent impl, for the methods returning `Self` in a type, `Result<T, Self>`.
+            #[doc = #doc]
+            #trait_vis trait #trait_name #impl_generics: ::core::marker::Sized #where_clause {
+                #(#declarations)*
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #trait_name #ty_generics for #name #ty_generics #where_clause {
+                #(#implementations)*
+            }
+        }
+    })
+}
+
+/// the signature of the method in the trait, without the patterns of the implementation, `mut self` or `mut value`,
+/// which aren't allowed in a function without a body.
+fn declaration(sig: &Signature) -> Signature {
+    let mut sig = sig.clone();
+    for input in &mut sig.inputs {
+        match input {
+            // the `mut` of `&mut self` is part of the type.
+            FnArg::Receiver(receiver) if receiver.reference.is_none() => receiver.mutability = None,
+            FnArg::Receiver(_) => {},
+            FnArg::Typed(arg) => match &mut *arg.pat {
+                Pat::Ident(pat) => {
+                    pat.by_ref = None;
+                    pat.mutability = None;
+                    pat.subpat = None;
+                },
+                pat => *pat = Pat::Wild(PatWild { attrs: Vec::new(), underscore_token: Default::default() }),
+            },
+        }
+    }
+    sig
+}
+
+/// the visibility to give to the items of the generated module, so they are visible
+/// where the enum is: the paths relative to the module of the enum get one more `super`.
+fn nested_visibility(vis: &Visibility) -> TokenStream {
+    match vis {
+        Visibility::Public(_) => quote! { pub },
+        Visibility::Inherited => quote! { pub(super) },
+        Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            match path.segments.first() {
+                Some(first) if first.ident == "crate" || path.leading_colon.is_some() => quote! { #vis },
+                Some(first) if first.ident == "self" => {
+                    let rest = path.segments.iter().skip(1);
+                    quote! { pub(in super #(::#rest)*) }
+                },
+                _ => quote! { pub(in super::#path) },
+            }
+        },
+    }
+}
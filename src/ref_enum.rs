@@ -0,0 +1,126 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, DeriveInput, GenericParam, Ident};
+
+use crate::expand::{replace_self, LIFETIME};
+use crate::variant::{Style, Variant};
+
+/// the names of a borrowed view of the enum and of the method building it.
+pub(crate) struct RefEnum {
+    /// the container option generating it.
+    pub option: &'static str,
+    /// appended to the name of the enum.
+    pub suffix: &'static str,
+    pub method: &'static str,
+}
+
+/// `#[expect_macro(ref_enum)]`: `{enum_name}Ref`, built by `as_ref_enum(&self)`.
+pub(crate) const REF_ENUM: RefEnum = RefEnum { option: "ref_enum", suffix: "Ref", method: "as_ref_enum" };
+
+/// `#[expect_macro(projection)]`: `{enum_name}Projection`, built by `project(&self)`.
+pub(crate) const PROJECTION: RefEnum = RefEnum { option: "projection", suffix: "Projection", method: "project" };
+
+/// a `{enum_name}Ref` enum mirroring the original one with references to the fields,
+/// with a `to_owned(&self)` method cloning them back, and the `as_ref_enum(&self)` method building it,
+/// or the same with the names of `names`.
+///
+/// returns the items to emit next to the enum, and the method.
+pub(crate) fn ref_enum(derive_input: &DeriveInput, variants: &[Variant], names: &RefEnum) -> syn::Result<(TokenStream, TokenStream)> {
+    let name = &derive_input.ident;
+    let vis = &derive_input.vis;
+    let ref_name = format_ident!("{}{}", name, names.suffix);
+    let method_name = Ident::new(names.method, name.span());
+
+    if variants.iter().all(|variant| variant.fields.is_empty()) {
+        let message = format!("`#[expect_macro({})]` needs a variant with fields", names.option);
+        return Err(syn::Error::new_spanned(name, message));
+    }
+
+    let lifetime = syn::Lifetime::new(LIFETIME, name.span());
+    let mut generics = derive_input.generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(parse_quote!(#lifetime)));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (_, enum_ty_generics, _) = derive_input.generics.split_for_impl();
+    let enum_ty = quote! { #name #enum_ty_generics };
+    let field_ty = |ty: &syn::Type| replace_self(ty.to_token_stream(), &enum_ty);
+
+    let ref_variants = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let tys = variant.all_fields().into_iter().map(|field| field_ty(&field.ty));
+        match variant.style {
+            Style::Named => {
+                let members = variant.all_fields().into_iter().map(|field| &field.member);
+                quote! { #variant_name { #(#members: &#lifetime #tys),* } }
+            },
+            Style::Unnamed => quote! { #variant_name( #(&#lifetime #tys),* ) },
+            Style::Unit => quote! { #variant_name },
+        }
+    });
+
+    let ref_path = quote! { #ref_name };
+    let name_path = quote! { #name };
+    let arms = variants.iter().map(|variant| {
+        let pattern = variant.with_values(&name_path, variant.all_fields().into_iter().map(|field| &field.binding));
+        let value = variant.with_values(&ref_path, variant.all_fields().into_iter().map(|field| &field.binding));
+        quote! { #pattern => #value }
+    });
+
+    let owned_arms = variants.iter().map(|variant| {
+        let pattern = variant.with_values(&ref_path, variant.all_fields().into_iter().map(|field| &field.binding));
+        let clones = variant.all_fields().into_iter().map(|field| {
+            let binding = &field.binding;
+            quote! { ::core::clone::Clone::clone(#binding) }
+        });
+        let value = variant.with_values(&name_path, clones);
+        quote! { #pattern => #value }
+    });
+
+    // the bounds are higher-ranked so they are only checked where the method is used,
+    // instead of failing the derive for fields of a concrete type which isn't `Clone`.
+    let clone_bounds = variants.iter().flat_map(|variant| variant.all_fields()).map(|field| {
+        let ty = field_ty(&field.ty);
+        quote! { for<'__clone> #ty: ::core::clone::Clone }
+    });
+
+    let doc = format!("A borrowed view of a [`{}`], built by `{}`.", name, names.method);
+    let items = quote! {
+        #[doc = #doc]
+        #vis enum #ref_name #impl_generics #where_clause {
+            #(#ref_variants,)*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::clone::Clone for #ref_name #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::marker::Copy for #ref_name #ty_generics #where_clause {}
+
+        #[automatically_derived]
+        impl #impl_generics #ref_name #ty_generics #where_clause {
+            /// Clones the borrowed fields back into an owned value.
+            pub fn to_owned(&self) -> #enum_ty
+            where
+                #(#clone_bounds,)*
+            {
+                match *self {
+                    #(#owned_arms,)*
+                }
+            }
+        }
+    };
+
+    let method = quote! {
+        pub fn #method_name<#lifetime>(&#lifetime self) -> #ref_name #ty_generics {
+            match self {
+                #(#arms,)*
+            }
+        }
+    };
+
+    Ok((items, method))
+}
@@ -0,0 +1,506 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, ExprLit, Fields, Generics, Ident, Lifetime, Lit, LitStr, Path, Token, Type};
+
+use crate::attr::VariantAttrs;
+
+/// the shape of a variant, used to build its patterns.
+pub(crate) enum Style {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+/// a field of a variant.
+pub(crate) struct Field {
+    /// the field name for named variants.
+    pub member: Option<Ident>,
+    /// the name of the method argument holding the expected value (`a`, `value_0`).
+    pub arg: Ident,
+    /// the name the field is bound to in the generated patterns (`attr_a`, `attr_0`).
+    /// it has a mixed-site span, so it can't collide with the arguments named after the fields.
+    pub binding: Ident,
+    pub ty: Type,
+    /// `#[ignore_eq]`: the field is extracted but not compared in the guard.
+    pub ignore_eq: bool,
+    /// `#[guard(epsilon = ...)]`: the float field is compared within this tolerance in the guard, instead of with `==`.
+    pub epsilon: Option<Expr>,
+    /// `#[rename = "..."]`: the name of the field in the name of its getter.
+    pub rename: Option<Ident>,
+    /// `#[as_dyn]` (or `#[as_str]` for a `String`): the methods returning a reference to the field return a reference
+    /// to its `Deref::Target`.
+    pub as_dyn: bool,
+    /// `#[iter]`: generate a method returning an iterator over the field by reference.
+    pub iter: bool,
+}
+
+impl Field {
+    /// the type of the reference to the field returned by the methods borrowing `self` for `lifetime`.
+    pub fn ref_ty(&self, lifetime: &Lifetime) -> TokenStream {
+        let ty = &self.ty;
+        if self.as_dyn {
+            quote! { &#lifetime <#ty as ::core::ops::Deref>::Target }
+        } else {
+            quote! { &#lifetime #ty }
+        }
+    }
+
+    /// the reference returned for the field, `ident` being the field bound by reference.
+    pub fn ref_value(&self, ident: &Ident) -> TokenStream {
+        if self.as_dyn {
+            quote! { ::core::ops::Deref::deref(#ident) }
+        } else {
+            quote! { #ident }
+        }
+    }
+
+    /// like `ref_ty`, for the methods borrowing `self` mutably.
+    pub fn mut_ty(&self, lifetime: &Lifetime) -> TokenStream {
+        let ty = &self.ty;
+        if self.as_dyn {
+            quote! { &#lifetime mut <#ty as ::core::ops::Deref>::Target }
+        } else {
+            quote! { &#lifetime mut #ty }
+        }
+    }
+
+    /// like `ref_value`, `ident` being the field bound by mutable reference.
+    pub fn mut_value(&self, ident: &Ident) -> TokenStream {
+        if self.as_dyn {
+            quote! { ::core::ops::DerefMut::deref_mut(#ident) }
+        } else {
+            quote! { #ident }
+        }
+    }
+}
+
+/// `#[return_struct]`: the methods returning the fields by value return them in a struct instead of a tuple.
+pub(crate) struct ReturnStruct {
+    /// the name of the struct, `{enum_name}{variant_name}` or `#[return_struct(name = "...")]`.
+    pub ident: Ident,
+    /// `#[return_struct(derive(...))]`: the traits derived for the struct.
+    pub derives: Vec<Path>,
+    /// the struct with the generics of the enum.
+    pub ty: TokenStream,
+}
+
+/// a variant of the enum, with everything the generated methods need.
+pub(crate) struct Variant {
+    pub ident: Ident,
+    /// the name of the variant in the names of the methods: the lowercased variant name, or its `#[rename = "..."]`.
+    pub name: String,
+    pub is_panic: bool,
+    /// `#[panic(with_location)]`: the panic messages contain the location of the caller.
+    pub panic_location: bool,
+    /// `#[collect]`: generate `into_{variant_name}_vec`.
+    pub collect: bool,
+    /// `#[no_debug]`: the panic messages of the methods of the variant only show the name of the found variant.
+    pub no_debug: bool,
+    pub style: Style,
+    /// the fields returned and compared by the generated methods, without the phantom fields.
+    pub fields: Vec<Field>,
+    /// `#[phantom]` and `PhantomData` fields, with their position in the variant: they are only matched with `_`
+    /// and built with `Default::default()`.
+    pub phantoms: Vec<(usize, Field)>,
+    pub return_struct: Option<ReturnStruct>,
+    /// `#[box_return]`: the methods returning the fields by value return them in a `Box`.
+    pub box_return: bool,
+    /// `#[extract(...)]`: the indices of the fields returned by `expect_{variant_name}_partial`, in the given order.
+    pub extract: Option<Vec<usize>>,
+    /// the explicit discriminant of the variant, `Variant = ...`.
+    pub discriminant: Option<Expr>,
+    /// the options given with `#[expect_macro(...)]` on the variant.
+    pub attrs: VariantAttrs,
+}
+
+impl Variant {
+    pub fn new(enum_name: &Ident, generics: &Generics, variant: &syn::Variant) -> syn::Result<Self> {
+        let panic = find_attr(&variant.attrs, "panic")?;
+        let is_panic = panic.is_some();
+        let panic_location = match panic {
+            Some(attr) => panic_location(attr)?,
+            None => false,
+        };
+        let collect = has_attr(&variant.attrs, "collect")?;
+        let no_debug = has_attr(&variant.attrs, "no_debug")?;
+        let box_return = has_attr(&variant.attrs, "box_return")?;
+        let name = match rename(&variant.attrs)? {
+            Some(name) => name.unraw().to_string(),
+            None => {
+                let name = variant.ident.unraw().to_string();
+                if !name.is_ascii() {
+                    let message = format!("`{}` can't be used in the names of the methods, rename it with `#[rename = \"...\"]`", name);
+                    return Err(syn::Error::new_spanned(&variant.ident, message));
+                }
+                name.to_lowercase()
+            },
+        };
+        let mut attrs = VariantAttrs::from_attrs(&variant.attrs)?;
+        // `#[non_exhaustive]` has no effect in the crate of the enum, where the derive expands, so the variant could be built.
+        // it is named instead anyway, as the fields given to the method may not be all of its fields in a later version.
+        attrs.no_construct |= has_attr(&variant.attrs, "non_exhaustive")?;
+
+        let (style, all_fields) = match &variant.fields {
+            Fields::Named(named) => {
+                let fields = named.named.iter().map(|field| {
+                    let name = field.ident.clone().expect("Expected field name");
+                    // a raw field name, like `r#type`, can't be used in the name of the binding as is.
+                    let binding = Ident::new(&format!("attr_{}", name.unraw().to_string().to_lowercase()), Span::mixed_site().located_at(name.span()));
+                    Ok(Field {
+                        member: Some(name.clone()),
+                        arg: name,
+                        binding,
+                        ty: field.ty.clone(),
+                        ignore_eq: has_attr(&field.attrs, "ignore_eq")?,
+                        epsilon: epsilon(&field.attrs)?,
+                        rename: rename(&field.attrs)?,
+                        as_dyn: has_attr(&field.attrs, "as_dyn")? || as_str(field)?,
+                        iter: has_attr(&field.attrs, "iter")?,
+                    })
+                }).collect::<syn::Result<_>>()?;
+                (Style::Named, fields)
+            },
+            Fields::Unnamed(unnamed) => {
+                let fields = unnamed.unnamed.iter().enumerate().map(|(n, field)| {
+                    Ok(Field {
+                        member: None,
+                        arg: Ident::new(&format!("value_{}", n), enum_name.span()),
+                        binding: Ident::new(&format!("attr_{}", n), Span::mixed_site().located_at(enum_name.span())),
+                        ty: field.ty.clone(),
+                        ignore_eq: has_attr(&field.attrs, "ignore_eq")?,
+                        epsilon: epsilon(&field.attrs)?,
+                        rename: rename(&field.attrs)?,
+                        as_dyn: has_attr(&field.attrs, "as_dyn")? || as_str(field)?,
+                        iter: has_attr(&field.attrs, "iter")?,
+                    })
+                }).collect::<syn::Result<_>>()?;
+                (Style::Unnamed, fields)
+            },
+            Fields::Unit => (Style::Unit, Vec::new()),
+        };
+
+        let mut phantoms = Vec::new();
+        let mut fields = Vec::new();
+        for (position, (field, syn_field)) in all_fields.into_iter().zip(&variant.fields).enumerate() {
+            if has_attr(&syn_field.attrs, "phantom")? || is_phantom_data(&syn_field.ty) {
+                phantoms.push((position, field));
+            } else {
+                fields.push(field);
+            }
+        }
+
+        if let Some(attr) = find_attr(&variant.attrs, "stable_order")? {
+            if let Style::Named = style {
+                fields.sort_by_cached_key(|field| field.arg.unraw().to_string());
+            } else {
+                return Err(syn::Error::new_spanned(attr, "`#[stable_order]` is only supported on variants with named fields"));
+            }
+        }
+
+        if let Some(attr) = find_attr(&variant.attrs, "iter")? {
+            match fields.as_mut_slice() {
+                [field] => field.iter = true,
+                _ => return Err(syn::Error::new_spanned(attr, "`#[iter]` on a variant needs a variant with a single field, put it on a field instead")),
+            }
+        }
+
+        let return_struct = match find_attr(&variant.attrs, "return_struct")? {
+            Some(attr) => {
+                if let Style::Unit = style {
+                    return Err(syn::Error::new_spanned(attr, "`#[return_struct]` needs a variant with at least one field"));
+                }
+                Some(return_struct(enum_name, generics, &variant.ident, attr)?)
+            },
+            None => None,
+        };
+
+        // the returned tuple would be usable, but not printable or comparable, which only fails where it is used.
+        if return_struct.is_none() && fields.len() > 12 {
+            let message = format!(
+                "`{}::{}` has {} fields, but `Debug` and `PartialEq` are only implemented for the tuples of up to 12 fields, \
+                 add `#[return_struct(derive(Debug, PartialEq))]` to return a struct instead",
+                enum_name, variant.ident, fields.len(),
+            );
+            return Err(syn::Error::new_spanned(&variant.ident, message));
+        }
+
+        let extract = match find_attr(&variant.attrs, "extract")? {
+            Some(attr) => Some(extract(attr, &style, &fields)?),
+            None => None,
+        };
+
+        Ok(Variant {
+            ident: variant.ident.clone(),
+            name,
+            is_panic,
+            panic_location,
+            collect,
+            no_debug,
+            style,
+            fields,
+            phantoms,
+            return_struct,
+            box_return,
+            extract,
+            discriminant: variant.discriminant.as_ref().map(|(_, discriminant)| discriminant.clone()),
+            attrs,
+        })
+    }
+
+    /// the fields compared to the arguments of the guarded methods.
+    pub fn guarded_fields(&self) -> impl Iterator<Item = &Field> {
+        self.fields.iter().filter(|field| !field.ignore_eq)
+    }
+
+    /// the name of a generated method, `{prefix}_{variant_name}`.
+    pub fn method_name(&self, prefix: &str) -> Ident {
+        let name = format!("{}_{}", prefix, self.name);
+        Ident::new(&name, self.ident.span())
+    }
+
+    /// every field of the variant, the phantom ones included, in declaration order (or in the `#[stable_order]`).
+    pub fn all_fields(&self) -> Vec<&Field> {
+        let mut fields = self.fields.iter().collect::<Vec<_>>();
+        for (position, phantom) in &self.phantoms {
+            fields.insert(*position, phantom);
+        }
+        fields
+    }
+
+    /// the value of `field` in the patterns and the expressions built with `with_values`: `value` for the returned
+    /// fields, and `phantom` for the phantom ones.
+    fn phantom_or<T: ToTokens>(&self, field: &Field, value: T, phantom: &TokenStream) -> TokenStream {
+        if self.phantoms.iter().any(|(_, candidate)| candidate.binding == field.binding) {
+            phantom.clone()
+        } else {
+            value.to_token_stream()
+        }
+    }
+
+    /// `path::variant` with each field of `all_fields` set to (or bound to) one of `values`.
+    pub fn with_values<T: ToTokens>(&self, path: &TokenStream, values: impl IntoIterator<Item = T>) -> TokenStream {
+        let variant_name = &self.ident;
+        let values = values.into_iter().collect::<Vec<_>>();
+        match self.style {
+            Style::Named => {
+                let members = self.all_fields().into_iter().map(|field| &field.member);
+                quote! { #path::#variant_name { #(#members: #values),* } }
+            },
+            Style::Unnamed => quote! { #path::#variant_name( #(#values),* ) },
+            Style::Unit => quote! { #path::#variant_name },
+        }
+    }
+
+    /// a pattern binding every field of the variant to its `binding` name, except the phantom fields.
+    pub fn pattern(&self, enum_name: &Ident) -> TokenStream {
+        let wildcard = quote! { _ };
+        let values = self.all_fields().into_iter().map(|field| self.phantom_or(field, &field.binding, &wildcard)).collect::<Vec<_>>();
+        self.with_values(&enum_name.to_token_stream(), values)
+    }
+
+    /// a pattern binding only the fields at `indices` (in `fields`) to their `binding` name.
+    pub fn partial_pattern(&self, enum_name: &Ident, indices: &[usize]) -> TokenStream {
+        let bound = indices.iter().map(|&index| &self.fields[index].binding).collect::<Vec<_>>();
+        let values = self.all_fields().into_iter().map(|field| {
+            if bound.contains(&&field.binding) {
+                field.binding.to_token_stream()
+            } else {
+                quote! { _ }
+            }
+        }).collect::<Vec<_>>();
+        self.with_values(&enum_name.to_token_stream(), values)
+    }
+
+    /// a pattern matching the variant whatever its fields are.
+    pub fn wildcard_pattern(&self, enum_name: &Ident) -> TokenStream {
+        let variant_name = &self.ident;
+        quote! { #enum_name::#variant_name { .. } }
+    }
+
+    /// the type returned by the methods returning the fields by value: the return struct, or a tuple.
+    pub fn returned_ty(&self) -> TokenStream {
+        let ty = match &self.return_struct {
+            Some(return_struct) => return_struct.ty.clone(),
+            None => {
+                let fields_ty = self.fields.iter().map(|field| &field.ty);
+                quote! { (#(#fields_ty),*) }
+            },
+        };
+
+        if self.box_return {
+            quote! { ::std::boxed::Box<#ty> }
+        } else {
+            ty
+        }
+    }
+
+    /// an expression building the value returned by the methods returning the fields by value from `values`.
+    pub fn returned<T: ToTokens>(&self, values: impl IntoIterator<Item = T>) -> TokenStream {
+        let values = values.into_iter().collect::<Vec<_>>();
+        let value = match &self.return_struct {
+            Some(return_struct) => {
+                let ident = &return_struct.ident;
+                match self.style {
+                    Style::Named => {
+                        let members = self.fields.iter().map(|field| &field.member);
+                        quote! { #ident { #(#members: #values),* } }
+                    },
+                    _ => quote! { #ident( #(#values),* ) },
+                }
+            },
+            None => quote! { (#(#values),*) },
+        };
+
+        if self.box_return {
+            quote! { ::std::boxed::Box::new(#value) }
+        } else {
+            value
+        }
+    }
+
+    /// an expression building the variant from the method arguments, through `Self`.
+    pub fn construct(&self) -> TokenStream {
+        let default = quote! { ::core::default::Default::default() };
+        let values = self.all_fields().into_iter().map(|field| self.phantom_or(field, &field.arg, &default)).collect::<Vec<_>>();
+        self.with_values(&quote! { Self }, values)
+    }
+}
+
+/// `#[return_struct]`, `#[return_struct(derive(...))]` or `#[return_struct(name = "...")]`.
+fn return_struct(enum_name: &Ident, generics: &Generics, variant_name: &Ident, attr: &Attribute) -> syn::Result<ReturnStruct> {
+    let mut ident = Ident::new(&format!("{}{}", enum_name, variant_name), variant_name.span());
+    let mut derives = Vec::new();
+
+    if let syn::Meta::List(_) = attr.meta {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("derive") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                derives.extend(Punctuated::<Path, Token![,]>::parse_terminated(&content)?);
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                ident = name.parse().map_err(|_| syn::Error::new_spanned(&name, "expected a valid struct name"))?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `return_struct` option"))
+            }
+        })?;
+    }
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let ty = quote! { #ident #ty_generics };
+
+    Ok(ReturnStruct { ident, derives, ty })
+}
+
+/// `#[extract(a, b)]`, the indices of the listed fields of a variant with named fields.
+fn extract(attr: &Attribute, style: &Style, fields: &[Field]) -> syn::Result<Vec<usize>> {
+    if let Style::Unit | Style::Unnamed = style {
+        return Err(syn::Error::new_spanned(attr, "`#[extract(...)]` is only supported on variants with named fields"));
+    }
+
+    let names = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+    if names.is_empty() {
+        return Err(syn::Error::new_spanned(attr, "`#[extract(...)]` needs at least one field"));
+    }
+
+    let mut indices = Vec::new();
+    for name in names {
+        match fields.iter().position(|field| field.member.as_ref() == Some(&name)) {
+            Some(index) if indices.contains(&index) => return Err(syn::Error::new_spanned(name, "field extracted more than once")),
+            Some(index) => indices.push(index),
+            None => return Err(syn::Error::new_spanned(&name, format!("no field `{}` in the variant", name))),
+        }
+    }
+    Ok(indices)
+}
+
+/// whether `ty` is a `PhantomData<...>`, a phantom field without `#[phantom]`.
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.segments.last().is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+fn has_attr(attrs: &[Attribute], name: &str) -> syn::Result<bool> {
+    Ok(find_attr(attrs, name)?.is_some())
+}
+
+/// the attribute named `name`, which can only be given once.
+fn find_attr<'a>(attrs: &'a [Attribute], name: &str) -> syn::Result<Option<&'a Attribute>> {
+    let mut found = attrs.iter().filter(|attr| attr.path().is_ident(name));
+    let attr = found.next();
+    match found.next() {
+        Some(duplicate) => Err(syn::Error::new_spanned(duplicate, format!("duplicate `#[{}]` attribute", name))),
+        None => Ok(attr),
+    }
+}
+
+/// whether the `#[panic]` attribute is `#[panic(with_location)]`.
+fn panic_location(attr: &Attribute) -> syn::Result<bool> {
+    let mut with_location = false;
+    if let syn::Meta::List(_) = attr.meta {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with_location") {
+                with_location = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `panic` option"))
+            }
+        })?;
+    }
+    Ok(with_location)
+}
+
+/// `#[as_str]`, only allowed on a `String` field, as written.
+fn as_str(field: &syn::Field) -> syn::Result<bool> {
+    let attr = match find_attr(&field.attrs, "as_str")? {
+        Some(attr) => attr,
+        None => return Ok(false),
+    };
+
+    let is_string = match &field.ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last().is_some_and(|segment| segment.ident == "String" && segment.arguments.is_none()),
+        _ => false,
+    };
+    if !is_string {
+        return Err(syn::Error::new_spanned(attr, "`#[as_str]` needs a `String` field, `#[as_dyn]` works for the other types implementing Deref"));
+    }
+    Ok(true)
+}
+
+/// `#[guard(epsilon = ...)]`, the tolerance of the comparison of a float field.
+fn epsilon(attrs: &[Attribute]) -> syn::Result<Option<Expr>> {
+    let attr = match find_attr(attrs, "guard")? {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+
+    let mut epsilon = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("epsilon") {
+            epsilon = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unknown `guard` option"))
+        }
+    })?;
+    Ok(epsilon)
+}
+
+/// `#[rename = "..."]`, the name to use instead of the name of the field or variant.
+fn rename(attrs: &[Attribute]) -> syn::Result<Option<Ident>> {
+    let attr = match find_attr(attrs, "rename")? {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+
+    match &attr.meta.require_name_value()?.value {
+        Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) => Ok(Some(name.parse()?)),
+        value => Err(syn::Error::new_spanned(value, "expected a string, `#[rename = \"...\"]`")),
+    }
+}
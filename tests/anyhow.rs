@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, Expect)]
+#[expect_macro(anyhow, cloned_accessors)]
+enum Command {
+    Move { x: i32, #[ignore_eq] y: i32 },
+    #[panic]
+    Quit,
+}
+
+fn move_y(command: &Command) -> anyhow::Result<i32> {
+    let (_, y) = command.expect_move_cloned(0)?;
+    Ok(y)
+}
+
+#[test]
+fn returns_ok_on_match() {
+    let command = Command::Move { x: 0, y: 3 };
+    assert_eq!(move_y(&command).unwrap(), 3);
+    assert_eq!(command.expect_move(0).unwrap(), (0, 3));
+}
+
+#[test]
+fn returns_error_on_mismatch() {
+    assert_eq!(move_y(&Command::Quit).unwrap_err().to_string(), "expected Command::Move");
+    assert_eq!(move_y(&Command::Move { x: 1, y: 3 }).unwrap_err().to_string(), "expected Command::Move");
+}
+
+#[test]
+fn panic_variants_still_panic() {
+    Command::Quit.expect_quit();
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(as_discriminant)]
+enum Event {
+    Key(char),
+    Click { x: i32, y: i32 },
+    Quit,
+}
+
+#[test]
+fn same_variant_same_discriminant() {
+    assert_eq!(Event::Key('a').as_discriminant(), Event::Key('b').as_discriminant());
+    assert_ne!(Event::Key('a').as_discriminant(), Event::Quit.as_discriminant());
+}
+
+#[test]
+fn discriminants_in_a_set() {
+    let events = [Event::Key('a'), Event::Click { x: 1, y: 2 }, Event::Key('b'), Event::Quit, Event::Quit];
+    let kinds = events.iter().map(Event::as_discriminant).collect::<HashSet<_>>();
+    assert_eq!(kinds.len(), 3);
+    assert!(kinds.contains(&Event::Click { x: 0, y: 0 }.as_discriminant()));
+}
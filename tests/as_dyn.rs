@@ -0,0 +1,49 @@
+use expect_macro_derive::Expect;
+
+trait Draw {
+    fn draw(&self) -> String;
+}
+
+struct Button(&'static str);
+
+impl Draw for Button {
+    fn draw(&self) -> String {
+        format!("[{}]", self.0)
+    }
+}
+
+#[derive(Expect)]
+#[expect_macro(as_ref, getters)]
+enum Widget {
+    Custom(#[as_dyn] #[ignore_eq] Box<dyn Draw>),
+    Labeled { #[as_dyn] label: String, #[as_dyn] #[ignore_eq] content: Box<dyn Draw> },
+    Empty,
+}
+
+#[test]
+fn as_ref_returns_trait_object() {
+    let widget = Widget::Custom(Box::new(Button("ok")));
+    let custom: &dyn Draw = widget.as_custom().unwrap();
+    assert_eq!(custom.draw(), "[ok]");
+    assert!(Widget::Empty.as_custom().is_none());
+}
+
+#[test]
+fn getters_return_deref_target() {
+    let widget = Widget::Labeled { label: "title".to_string(), content: Box::new(Button("body")) };
+    let label: &str = widget.get_labeled_label().unwrap();
+    assert_eq!(label, "title");
+    assert_eq!(widget.get_labeled_content().unwrap().draw(), "[body]");
+
+    let (label, content) = widget.as_labeled().unwrap();
+    assert_eq!(label, "title");
+    assert_eq!(content.draw(), "[body]");
+}
+
+#[test]
+fn expect_still_returns_the_fields() {
+    let widget = Widget::Labeled { label: "title".to_string(), content: Box::new(Button("body")) };
+    let (label, content): (String, Box<dyn Draw>) = widget.expect_labeled("title".to_string()).unwrap();
+    assert_eq!(label, "title");
+    assert_eq!(content.draw(), "[body]");
+}
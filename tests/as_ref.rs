@@ -0,0 +1,34 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(as_ref)]
+enum Foo {
+    Bar { a: i32, b: String },
+    Baz(i32),
+    Qux,
+}
+
+#[derive(Expect)]
+#[expect_macro(as_ref)]
+enum Borrowed<'a, T: PartialEq> {
+    Slice(&'a [T]),
+    Value(T),
+}
+
+#[test]
+fn as_ref_borrows_fields() {
+    let bar = Foo::Bar { a: 1, b: "b".to_string() };
+    assert_eq!(bar.as_bar(), Some((&1, &"b".to_string())));
+    assert_eq!(bar.as_baz(), None);
+    assert_eq!(Foo::Baz(2).as_baz(), Some(&2));
+    assert_eq!(Foo::Qux.as_qux(), Some(()));
+}
+
+#[test]
+fn as_ref_with_enum_lifetime() {
+    let values = [1, 2, 3];
+    let slice = Borrowed::Slice(&values[..]);
+    let inner: &[i32] = slice.as_slice().copied().unwrap();
+    assert_eq!(inner, &[1, 2, 3]);
+    assert_eq!(Borrowed::<i32>::Value(4).as_value(), Some(&4));
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(as_ref, as_mut, getters, try_as)]
+enum User {
+    Named { #[as_str] name: String, age: u32 },
+    Guest(#[as_str] std::string::String),
+    Anonymous,
+}
+
+#[test]
+fn string_field_as_str() {
+    let named = User::Named { name: "alice".to_string(), age: 30 };
+    let (name, age): (&str, &u32) = named.as_named().unwrap();
+    assert_eq!((name, age), ("alice", &30));
+    assert_eq!(named.get_named_name(), Some("alice"));
+    assert_eq!(User::Guest("bob".to_string()).as_guest(), Some("bob"));
+    assert_eq!(User::Guest("bob".to_string()).try_as_guest(), Ok("bob"));
+    assert_eq!(User::Anonymous.as_guest(), None);
+}
+
+#[test]
+fn by_value_methods_still_return_the_string() {
+    let mut guest = User::Guest("bob".to_string());
+    guest.as_mut_guest().unwrap().make_ascii_uppercase();
+    assert_eq!(guest.expect_guest("BOB".to_string()), Some("BOB".to_string()));
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(assertions)]
+enum Foo {
+    Bar { a: i32, b: i32 },
+    Baz(i32, i32),
+    Qux,
+}
+
+#[test]
+fn assert_matching_variant() {
+    Foo::Bar { a: 1, b: 2 }.assert_bar();
+    Foo::Baz(1, 2).assert_baz();
+    Foo::Qux.assert_qux();
+}
+
+#[test]
+#[should_panic(expected = "expected `Foo::Bar`, found `Foo::Baz`")]
+fn assert_other_variant() {
+    Foo::Baz(1, 2).assert_bar();
+}
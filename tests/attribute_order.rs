@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(as_ref)]
+enum PanicFirst {
+    #[panic]
+    #[cfg(test)]
+    /// a documented variant.
+    Bar { a: i32, #[ignore_eq] #[rename = "second"] b: i32 },
+    #[cfg(not(test))]
+    Removed(i32),
+    Qux,
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(as_ref)]
+enum PanicLast {
+    /// a documented variant.
+    #[cfg(test)]
+    #[panic]
+    Bar { a: i32, #[rename = "second"] #[ignore_eq] b: i32 },
+    Qux,
+    #[cfg(not(test))]
+    Removed(i32),
+}
+
+#[test]
+fn same_methods_whatever_the_order() {
+    assert_eq!(PanicFirst::Bar { a: 1, b: 2 }.expect_bar(1), (1, 2));
+    assert_eq!(PanicLast::Bar { a: 1, b: 2 }.expect_bar(1), (1, 2));
+    assert_eq!(PanicFirst::Qux.as_bar(), None);
+    assert_eq!(PanicLast::Qux.as_bar(), None);
+}
+
+#[test]
+#[should_panic(expected = "expected PanicFirst::Bar, found Qux")]
+fn panic_first() {
+    PanicFirst::Qux.expect_bar(1);
+}
+
+#[test]
+#[should_panic(expected = "expected PanicLast::Bar, found Qux")]
+fn panic_last() {
+    PanicLast::Qux.expect_bar(1);
+}
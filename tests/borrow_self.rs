@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(borrow_self)]
+enum Config {
+    Path { dir: String, file: String },
+    #[panic]
+    Port(u16),
+    Missing,
+}
+
+#[test]
+fn inspect_without_consuming() {
+    let config = Config::Path { dir: "/etc".to_string(), file: "a.toml".to_string() };
+    assert_eq!(config.expect_path("/etc".to_string(), "a.toml".to_string()), Some(("/etc".to_string(), "a.toml".to_string())));
+    assert_eq!(config.expect_path("/usr".to_string(), "a.toml".to_string()), None);
+    assert!(config.expect_missing().is_none());
+
+    let port = Config::Port(80);
+    assert_eq!(port.expect_port(80), 80);
+    assert_eq!(port.expect_port(80), 80);
+}
+
+#[test]
+#[should_panic(expected = "expected Port(80), found Port(81)")]
+fn panic_variant_borrowed() {
+    Config::Port(81).expect_port(80);
+}
@@ -0,0 +1,31 @@
+use std::fmt::Debug;
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(cloned_accessors, bound = "T: Clone + PartialEq")]
+enum Cell<T> {
+    Value(T),
+    Empty,
+}
+
+#[test]
+fn cloned_accessor_with_extra_bound() {
+    let cell = Cell::Value("a".to_string());
+    assert_eq!(cell.expect_value_cloned("a".to_string()), Some("a".to_string()));
+    assert_eq!(Cell::<String>::Empty.expect_value_cloned("a".to_string()), None);
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(module = "pair_ext", bound = "A: Debug + PartialEq", bound = "B: Debug + PartialEq")]
+enum Pair<A, B> {
+    #[panic]
+    Both(A, B),
+}
+
+#[test]
+fn bounds_on_the_trait_impl() {
+    use pair_ext::PairExt;
+
+    assert_eq!(Pair::Both(1, "b").expect_both(1, "b"), (1, "b"));
+}
@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq)]
+struct Frame {
+    pixels: [u32; 1024],
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(into, unwrap)]
+enum Packet {
+    #[box_return]
+    Video(Frame, u64),
+    #[box_return]
+    #[return_struct(derive(Debug, PartialEq))]
+    Audio { samples: [i16; 2048] },
+    Ping(u8),
+}
+
+#[test]
+fn boxed_tuple() {
+    let video: Box<(Frame, u64)> = Packet::Video(Frame { pixels: [1; 1024] }, 7).into_video().unwrap();
+    assert_eq!(video.0.pixels[0], 1);
+    assert_eq!(video.1, 7);
+    assert!(Packet::Ping(1).into_video().is_none());
+}
+
+#[test]
+fn boxed_struct() {
+    let audio = Packet::Audio { samples: [2; 2048] }.unwrap_audio();
+    assert_eq!(audio, Box::new(PacketAudio { samples: [2; 2048] }));
+}
+
+#[test]
+fn other_variants_are_not_boxed() {
+    assert_eq!(Packet::Ping(1).expect_ping(1), Some(1));
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(by_type)]
+enum Value {
+    Name(String),
+    Alias { alias: String },
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+    Pair(String, String),
+    Empty,
+}
+
+#[test]
+fn first_of_type() {
+    assert_eq!(Value::Name("a".to_string()).first_string(), Some("a".to_string()));
+    assert_eq!(Value::Alias { alias: "b".to_string() }.first_string(), Some("b".to_string()));
+    assert_eq!(Value::Pair("c".to_string(), "d".to_string()).first_string(), None);
+    assert_eq!(Value::Bytes(vec![1]).first_string(), None);
+    assert_eq!(Value::Empty.first_string(), None);
+    assert_eq!(Value::Path(PathBuf::from("/")).first_path_buf(), Some(PathBuf::from("/")));
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(clone_on_extract, into, unwrap, try_get, or_default, predicate_accessors, detailed, by_type)]
+enum Config {
+    Path(String),
+    #[extract(port)]
+    Server { host: String, port: u16 },
+    #[collect]
+    Pair(u8, u8),
+    #[panic]
+    Fatal(String),
+    Unset,
+}
+
+#[test]
+fn the_enum_survives_extraction() {
+    let path = Config::Path("a".to_string());
+    assert_eq!(path.expect_path("a".to_string()), Some("a".to_string()));
+    assert_eq!(path.into_path(), Some("a".to_string()));
+    assert_eq!(path.unwrap_path(), "a");
+    assert_eq!(path.try_get_path(), Ok("a".to_string()));
+    assert_eq!(path.try_get_pair(), Err("Path"));
+    assert_eq!(path.expect_path_or_default(), "a");
+    assert_eq!(path.expect_path_if(|path| path.len() == 1), Some("a".to_string()));
+    assert_eq!(path.expect_path_detailed("b".to_string()), ConfigMatchResult::ValueMismatch("a".to_string()));
+    assert_eq!(path.first_string(), Some("a".to_string()));
+    assert_eq!(path, Config::Path("a".to_string()));
+}
+
+#[test]
+fn partial_and_collect() {
+    let server = Config::Server { host: "h".to_string(), port: 80 };
+    assert_eq!(server.expect_server_partial(), Some(80));
+    assert_eq!(server.expect_server("h".to_string(), 80), Some(("h".to_string(), 80)));
+    assert_eq!(server, Config::Server { host: "h".to_string(), port: 80 });
+
+    let pair = Config::Pair(1, 2);
+    assert_eq!(pair.into_pair_vec(), Some(vec![1, 2]));
+    assert_eq!(pair.into_pair(), Some((1, 2)));
+    assert_eq!(Config::Unset.into_pair(), None);
+}
+
+#[test]
+fn panic_variant() {
+    let fatal = Config::Fatal("e".to_string());
+    assert_eq!(fatal.expect_fatal_if(|message| message == "e"), "e");
+    assert_eq!(fatal.expect_fatal("e".to_string()), "e");
+    assert_eq!(fatal, Config::Fatal("e".to_string()));
+}
@@ -0,0 +1,35 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(cloned_accessors)]
+enum User {
+    Named { id: u32, name: String },
+    #[panic]
+    Admin(String),
+    Anonymous,
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(cloned_accessors, eq_ref)]
+enum Borrowed {
+    Name(String),
+}
+
+#[test]
+fn cloned_accessor_keeps_self() {
+    let user = User::Named { id: 1, name: "alice".to_string() };
+    assert_eq!(user.expect_named_cloned(1, "alice".to_string()), Some((1, "alice".to_string())));
+    assert_eq!(user.expect_named_cloned(2, "alice".to_string()), None);
+    assert_eq!(user.expect_anonymous_cloned(), None);
+    assert_eq!(user.expect_named(1, "alice".to_string()), Some((1, "alice".to_string())));
+
+    let admin = User::Admin("root".to_string());
+    assert_eq!(admin.expect_admin_cloned("root".to_string()), "root");
+    assert_eq!(User::Anonymous.expect_anonymous_cloned(), Some(()));
+}
+
+#[test]
+fn cloned_accessor_with_eq_ref() {
+    let name = Borrowed::Name("bob".to_string());
+    assert_eq!(name.expect_name_cloned(&"bob".to_string()), Some("bob".to_string()));
+}
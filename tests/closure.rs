@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(into, as_mut, as_ref, is, predicate_accessors)]
+enum Callback {
+    On(Box<dyn FnMut() -> u32>),
+    Named(#[ignore_eq] Box<dyn Fn(u32) -> u32>, &'static str),
+    Off,
+}
+
+fn counter() -> Callback {
+    let mut count = 0;
+    Callback::On(Box::new(move || {
+        count += 1;
+        count
+    }))
+}
+
+#[test]
+fn call_through_as_mut() {
+    let mut callback = counter();
+    let on = callback.as_mut_on().unwrap();
+    assert_eq!(on(), 1);
+    assert_eq!(on(), 2);
+    assert_eq!(callback.as_mut_on().map(|on| on()), Some(3));
+    assert!(Callback::Off.as_mut_on().is_none());
+}
+
+#[test]
+fn call_after_into() {
+    let mut on = counter().into_on().unwrap();
+    assert_eq!(on(), 1);
+    assert!(Callback::Off.into_on().is_none());
+}
+
+#[test]
+fn guards_without_comparing_the_closure() {
+    assert!(counter().is_on());
+    assert!(counter().expect_on_if(|_| true).is_some());
+
+    let named = || Callback::Named(Box::new(|n| n * 2), "double");
+    let (double, _) = named().expect_named("double").unwrap();
+    assert_eq!(double(2), 4);
+    assert!(named().expect_named("triple").is_none());
+    assert_eq!(named().as_named().map(|(f, _)| f(3)), Some(6));
+    assert_eq!(named().expect_named_if(|f, name| f(1) == 2 && *name == "double").map(|(_, name)| name), Some("double"));
+}
@@ -0,0 +1,14 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Shape {
+    #[collect]
+    Points(i32, i32, i32),
+    Empty,
+}
+
+#[test]
+fn collect_homogeneous_fields() {
+    assert_eq!(Shape::Points(1, 2, 3).into_points_vec(), Some(vec![1, 2, 3]));
+    assert_eq!(Shape::Empty.into_points_vec(), None);
+}
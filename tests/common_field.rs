@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[common_field(id, name)]
+enum Entity {
+    User { id: u64, name: String },
+    Group { id: u64, members: Vec<u64> },
+    Guest { name: String },
+}
+
+#[test]
+fn shared_field() {
+    let user = Entity::User { id: 1, name: "a".to_string() };
+    let group = Entity::Group { id: 2, members: vec![] };
+    let guest = Entity::Guest { name: "b".to_string() };
+    assert_eq!(user.id(), Some(&1));
+    assert_eq!(group.id(), Some(&2));
+    assert_eq!(guest.id(), None);
+}
+
+#[test]
+fn several_common_fields() {
+    assert_eq!(Entity::User { id: 1, name: "a".to_string() }.name(), Some(&"a".to_string()));
+    assert_eq!(Entity::Guest { name: "b".to_string() }.name(), Some(&"b".to_string()));
+    assert_eq!(Entity::Group { id: 2, members: vec![] }.name(), None);
+}
+
+#[derive(Expect)]
+#[common_field(id)]
+enum Every {
+    A { id: u8 },
+    B { id: u8, extra: bool },
+}
+
+#[test]
+fn every_variant_has_the_field() {
+    assert_eq!(Every::A { id: 1 }.id(), Some(&1));
+    assert_eq!(Every::B { id: 2, extra: true }.id(), Some(&2));
+}
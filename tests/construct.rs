@@ -0,0 +1,39 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(into)]
+enum Foo {
+    #[expect_macro(construct)]
+    Bar(i32, i32),
+    #[expect_macro(construct)]
+    Baz { a: String, b: Option<u8> },
+    #[expect_macro(construct)]
+    Type(char),
+    #[expect_macro(construct)]
+    #[rename = "krate"]
+    Crate(i32),
+    Qux,
+}
+
+#[test]
+fn construct_tuple_variant() {
+    assert_eq!(Foo::bar(1, 2).into_bar(), Some((1, 2)));
+}
+
+#[test]
+fn construct_named_variant() {
+    let baz = Foo::baz("a".to_string(), None);
+    assert_eq!(baz, Foo::Baz { a: "a".to_string(), b: None });
+    assert_eq!(baz.into_baz(), Some(("a".to_string(), None)));
+}
+
+#[test]
+fn construct_keyword_variant() {
+    assert_eq!(Foo::r#type('t'), Foo::Type('t'));
+    assert_eq!(Foo::Qux.into_type(), None);
+}
+
+#[test]
+fn construct_renamed_path_keyword_variant() {
+    assert_eq!(Foo::krate(1), Foo::Crate(1));
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(contains)]
+enum Tag {
+    Id(u64),
+    Name { value: String },
+    #[extract(key)]
+    Pair { key: &'static str, value: Vec<u8> },
+    Both(u64, String),
+    Empty,
+}
+
+#[test]
+fn single_field_membership() {
+    assert!(Tag::Id(1).contains_id(&1));
+    assert!(!Tag::Id(1).contains_id(&2));
+    assert!(!Tag::Empty.contains_id(&1));
+    assert!(Tag::Name { value: "a".to_string() }.contains_name(&"a".to_string()));
+}
+
+#[test]
+fn extracted_field_membership() {
+    let pair = Tag::Pair { key: "k", value: vec![1] };
+    assert!(pair.contains_pair(&"k"));
+    assert!(!pair.contains_pair(&"v"));
+}
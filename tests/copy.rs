@@ -0,0 +1,18 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, Copy, PartialEq, Expect)]
+#[expect_macro(into, unwrap)]
+enum Point {
+    TwoD { x: i32, y: i32 },
+    Origin,
+}
+
+#[test]
+fn original_is_usable_after_into() {
+    let point = Point::TwoD { x: 1, y: 2 };
+    assert_eq!(point.into_twod(), Some((1, 2)));
+    assert_eq!(point.expect_twod(1, 2), Some((1, 2)));
+    assert_eq!(point.unwrap_twod(), (1, 2));
+    assert_eq!(point, Point::TwoD { x: 1, y: 2 });
+    assert_eq!(Point::Origin.into_twod(), None);
+}
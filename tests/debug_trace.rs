@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+
+use std::process::Command;
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(debug_trace, cloned_accessors)]
+enum Reply {
+    Status(u16),
+    Body { text: String },
+    Empty,
+}
+
+/// runs `test` of this binary in a child process, returning what it printed to stderr.
+fn child_stderr(test: &str) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([test, "--exact", "--nocapture", "--test-threads=1"])
+        .env("EXPECT_TRACE_CHILD", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn child() {
+    if std::env::var_os("EXPECT_TRACE_CHILD").is_none() {
+        return;
+    }
+    assert_eq!(Reply::Status(404).expect_status(200), None);
+    assert_eq!(Reply::Body { text: "a".to_string() }.expect_body_cloned("b".to_string()), None);
+    assert_eq!(Reply::Empty.expect_status(200), None);
+    assert_eq!(Reply::Status(200).expect_status(200), Some(200));
+}
+
+#[test]
+fn values_mismatch_is_traced() {
+    let stderr = child_stderr("child");
+    let traced = stderr.lines().filter(|line| line.contains("fields differ")).collect::<Vec<_>>();
+
+    if cfg!(debug_assertions) {
+        assert_eq!(traced, [
+            "expect_status: the value is Reply::Status, but its fields differ from the expected values",
+            "expect_body_cloned: the value is Reply::Body, but its fields differ from the expected values",
+        ]);
+    } else {
+        assert!(traced.is_empty());
+    }
+}
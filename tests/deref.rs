@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(deref)]
+enum Name {
+    Name(String),
+}
+
+#[derive(Expect)]
+#[expect_macro(deref)]
+enum Wrapper<T> {
+    Inner { value: Vec<T> },
+}
+
+#[test]
+fn call_a_method_of_the_field() {
+    let name = Name::Name("Ferris".to_string());
+    assert_eq!(name.len(), 6);
+    assert!(name.starts_with("Fe"));
+    assert_eq!(*name, "Ferris");
+}
+
+#[test]
+fn generic_enum() {
+    let wrapper = Wrapper::Inner { value: vec![1, 2, 3] };
+    assert_eq!(wrapper.iter().sum::<i32>(), 6);
+    assert_eq!(wrapper.expect_inner(vec![1, 2, 3]), Some(vec![1, 2, 3]));
+}
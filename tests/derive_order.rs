@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+use serde::{Deserialize, Serialize};
+
+// the same enum, with `Expect` first, in the middle and last among the derives.
+macro_rules! event {
+    ($name:ident, $(#[$derive:meta])*) => {
+        $(#[$derive])*
+        #[serde(tag = "type")]
+        #[expect_macro(as_ref, is)]
+        enum $name {
+            #[panic]
+            #[serde(rename = "click")]
+            Click { x: i32, #[ignore_eq] #[serde(default)] y: i32 },
+            #[serde(rename = "quit")]
+            Quit,
+        }
+    };
+}
+
+event!(ExpectFirst, #[derive(Expect, Debug, Clone, PartialEq, Serialize, Deserialize)]);
+event!(ExpectMiddle, #[derive(Debug, Clone, Expect, Serialize)] #[derive(PartialEq, Deserialize)]);
+event!(ExpectLast, #[derive(Deserialize, Serialize, PartialEq, Clone, Debug, Expect)]);
+
+#[test]
+fn same_methods_whatever_the_position() {
+    let first: ExpectFirst = serde_json::from_str(r#"{"type": "click", "x": 1}"#).unwrap();
+    let middle: ExpectMiddle = serde_json::from_str(r#"{"type": "click", "x": 1}"#).unwrap();
+    let last: ExpectLast = serde_json::from_str(r#"{"type": "click", "x": 1}"#).unwrap();
+
+    assert_eq!(first.as_click(), Some((&1, &0)));
+    assert_eq!(middle.as_click(), Some((&1, &0)));
+    assert_eq!(last.as_click(), Some((&1, &0)));
+    assert_eq!(first.clone().expect_click(1), (1, 0));
+    assert_eq!(middle.clone().expect_click(1), (1, 0));
+    assert_eq!(last.clone().expect_click(1), (1, 0));
+    assert!(!first.is_quit() && !middle.is_quit() && !last.is_quit());
+}
+
+#[test]
+fn other_derives_see_the_helper_attributes_as_is() {
+    let quit = ExpectLast::Quit;
+    assert_eq!(serde_json::to_string(&quit).unwrap(), r#"{"type":"quit"}"#);
+    assert_eq!(serde_json::to_string(&ExpectFirst::Quit).unwrap(), r#"{"type":"quit"}"#);
+    assert_eq!(quit.clone(), ExpectLast::Quit);
+}
+
+#[test]
+#[should_panic(expected = "expected ExpectLast::Click, found Quit")]
+fn panic_attribute_not_consumed() {
+    ExpectLast::Quit.expect_click(1);
+}
+
+#[test]
+#[should_panic(expected = "expected ExpectFirst::Click, found Quit")]
+fn panic_attribute_not_consumed_when_first() {
+    ExpectFirst::Quit.expect_click(1);
+}
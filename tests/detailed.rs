@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(detailed)]
+enum Event {
+    Key { code: u32, shift: bool },
+    Click(i32, i32),
+    Resize(#[ignore_eq] u32),
+    Quit,
+}
+
+#[test]
+fn all_outcomes() {
+    let key = || Event::Key { code: 1, shift: false };
+    assert_eq!(key().expect_key_detailed(1, false), EventMatchResult::Match((1, false)));
+    assert_eq!(key().expect_key_detailed(1, true), EventMatchResult::ValueMismatch((1, false)));
+    assert_eq!(Event::Quit.expect_key_detailed(1, false), EventMatchResult::VariantMismatch);
+}
+
+#[test]
+fn nothing_to_compare() {
+    assert_eq!(Event::Resize(3).expect_resize_detailed(), EventMatchResult::Match(3));
+    assert_eq!(Event::Quit.expect_quit_detailed(), EventMatchResult::Match(()));
+    assert_eq!(Event::Click(1, 2).expect_resize_detailed(), EventMatchResult::VariantMismatch);
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(discriminant_eq, discriminant_index)]
+enum Foo {
+    Bar { a: i32 },
+    Baz(String),
+    Qux,
+}
+
+#[test]
+fn eq_variant_ignores_fields() {
+    assert!(Foo::Bar { a: 1 }.eq_variant(&Foo::Bar { a: 2 }));
+    assert!(Foo::Baz("a".to_string()).eq_variant(&Foo::Baz("b".to_string())));
+    assert!(Foo::Qux.eq_variant(&Foo::Qux));
+    assert!(!Foo::Bar { a: 1 }.eq_variant(&Foo::Qux));
+    assert!(!Foo::Baz("a".to_string()).eq_variant(&Foo::Bar { a: 1 }));
+}
+
+#[test]
+fn discriminant_index_in_declaration_order() {
+    assert_eq!(Foo::Bar { a: 1 }.discriminant_index(), 0);
+    assert_eq!(Foo::Baz("a".to_string()).discriminant_index(), 1);
+    assert_eq!(Foo::Qux.discriminant_index(), 2);
+}
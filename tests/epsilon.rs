@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(cloned_accessors)]
+enum Measure {
+    Length(#[guard(epsilon = 0.001)] f64),
+    Point {
+        #[guard(epsilon = 1e-6)]
+        x: f32,
+        label: String,
+    },
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(eq_impl)]
+enum Loose {
+    Value(#[guard(epsilon = 0.5)] f64, u8),
+}
+
+#[test]
+fn within_tolerance() {
+    assert_eq!(Measure::Length(1.0).expect_length(1.0005), Some(1.0));
+    assert_eq!(Measure::Length(1.0).expect_length(0.9995), Some(1.0));
+    assert_eq!(Measure::Length(0.1 + 0.2).expect_length_cloned(0.3), Some(0.1 + 0.2));
+}
+
+#[test]
+fn outside_tolerance() {
+    assert_eq!(Measure::Length(1.0).expect_length(1.01), None);
+    let point = Measure::Point { x: 1.0, label: "a".to_string() };
+    assert_eq!(point.expect_point(1.1, "a".to_string()), None);
+}
+
+#[test]
+fn other_fields_still_compared() {
+    let point = || Measure::Point { x: 1.0, label: "a".to_string() };
+    assert_eq!(point().expect_point(1.0, "a".to_string()), Some((1.0, "a".to_string())));
+    assert_eq!(point().expect_point(1.0, "b".to_string()), None);
+}
+
+#[test]
+fn with_eq_impl() {
+    assert_eq!(Loose::Value(1.0, 2).expect_value(1.25, 2u8), Some((1.0, 2)));
+    assert_eq!(Loose::Value(1.0, 2).expect_value(2.0, 2u8), None);
+}
@@ -0,0 +1,46 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, Expect)]
+#[expect_macro(eq_impl, cloned_accessors, mut_accessors)]
+enum User {
+    Named { name: String, age: u32 },
+    #[panic]
+    Guest(String),
+}
+
+#[test]
+fn string_field_compared_with_str() {
+    let user = User::Named { name: "alice".to_string(), age: 30 };
+    assert_eq!(user.expect_named_cloned("alice", 30), Some(("alice".to_string(), 30)));
+    assert_eq!(user.expect_named_cloned("bob", 30), None);
+    assert_eq!(user.expect_named("alice", 30), Some(("alice".to_string(), 30)));
+}
+
+#[test]
+fn mutable_accessor() {
+    let mut user = User::Named { name: "alice".to_string(), age: 30 };
+    *user.expect_named_mut(&"alice", &30).unwrap().1 += 1;
+    assert_eq!(user.expect_named("alice", 31), Some(("alice".to_string(), 31)));
+}
+
+#[test]
+#[should_panic(expected = "expected User::Guest, found Guest(\"bob\")")]
+fn panic_message_names_the_variant() {
+    User::Guest("bob".to_string()).expect_guest("alice");
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(eq_impl, mut_accessors, detailed)]
+enum Reading {
+    Sample { name: String, value: u64 },
+}
+
+#[test]
+fn argument_types_given_with_turbofish() {
+    let reading = Reading::Sample { name: "a".to_string(), value: 1 };
+    assert!(matches!(reading.expect_sample_detailed::<&str, u64>("a", Default::default()), ReadingMatchResult::ValueMismatch(_)));
+
+    let mut reading = Reading::Sample { name: "a".to_string(), value: 1 };
+    *reading.expect_sample_mut::<&str, u64>(&"a", &1u8.into()).unwrap().1 += 1;
+    assert_eq!(reading.expect_sample::<String, u64>("a".into(), 2u8.into()), Some(("a".to_string(), 2)));
+}
@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use expect_macro_derive::Expect;
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq)]
+struct Payload(Vec<u64>);
+
+impl Clone for Payload {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+        Payload(self.0.clone())
+    }
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(eq_ref)]
+enum Message {
+    Data { id: u32, payload: Payload },
+    #[panic]
+    Checked(Payload),
+    Empty,
+}
+
+#[test]
+fn guard_borrows_without_cloning() {
+    let expected = Payload((0..1024).collect());
+
+    for _ in 0..1000 {
+        let message = Message::Data { id: 1, payload: Payload((0..1024).collect()) };
+        assert_eq!(message.expect_data(&2, &expected), None);
+
+        let message = Message::Data { id: 1, payload: Payload(vec![]) };
+        assert_eq!(message.expect_data(&1, &expected), None);
+
+        assert_eq!(Message::Empty.expect_data(&1, &expected), None);
+    }
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+
+    let message = Message::Data { id: 1, payload: Payload((0..1024).collect()) };
+    let (id, payload) = message.expect_data(&1, &expected).unwrap();
+    assert_eq!(id, 1);
+    assert_eq!(payload, expected);
+
+    let checked = Message::Checked(Payload(vec![1])).expect_checked(&Payload(vec![1]));
+    assert_eq!(checked, Payload(vec![1]));
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+trait ApproxEq {
+    fn approx_eq(&self, other: &Self) -> bool;
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &Self) -> bool {
+        (self - other).abs() < 1e-9
+    }
+}
+
+impl ApproxEq for String {
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.eq_ignore_ascii_case(other)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(eq_trait = "ApproxEq", cloned_accessors, mut_accessors)]
+enum Reading {
+    Sensor { name: String, value: f64 },
+    #[panic]
+    Offset(f64),
+}
+
+mod compare {
+    pub trait CaseInsensitive {
+        fn same(&self, other: &Self) -> bool;
+    }
+
+    impl CaseInsensitive for String {
+        fn same(&self, other: &Self) -> bool {
+            self.to_lowercase() == other.to_lowercase()
+        }
+    }
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(eq_trait = "compare::CaseInsensitive", eq_method = "same", eq_ref)]
+enum Command {
+    Run(String),
+}
+
+#[test]
+fn fields_compared_with_the_trait() {
+    let reading = Reading::Sensor { name: "Temp".to_string(), value: 0.1 + 0.2 };
+    assert_eq!(reading.expect_sensor_cloned("temp".to_string(), 0.3), Some(("Temp".to_string(), 0.1 + 0.2)));
+    assert_eq!(reading.expect_sensor_cloned("pressure".to_string(), 0.3), None);
+    assert_eq!(reading.expect_sensor_cloned("temp".to_string(), 0.4), None);
+    assert_eq!(reading.expect_sensor("TEMP".to_string(), 0.3), Some(("Temp".to_string(), 0.1 + 0.2)));
+}
+
+#[test]
+fn mutable_accessor() {
+    let mut reading = Reading::Offset(0.1 + 0.2);
+    *reading.expect_offset_mut(&0.3) = 1.0;
+    assert_eq!(reading.expect_offset(1.0), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "expected Offset(0.4), found Offset(0.30000000000000004)")]
+fn panics_when_the_trait_method_returns_false() {
+    Reading::Offset(0.1 + 0.2).expect_offset(0.4);
+}
+
+#[test]
+fn method_given_with_eq_method() {
+    let expected = "cargo".to_string();
+    assert_eq!(Command::Run("Cargo".to_string()).expect_run(&expected), Some("Cargo".to_string()));
+    assert_eq!(Command::Run("rustc".to_string()).expect_run(&expected), None);
+}
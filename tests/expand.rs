@@ -0,0 +1,6 @@
+// needs `cargo expand`, the snapshots are written with `MACROTEST=overwrite`.
+// a new feature gets its own file in `tests/expand`, or extends one of the existing ones.
+#[test]
+fn expansion() {
+    macrotest::expand("tests/expand/*.rs");
+}
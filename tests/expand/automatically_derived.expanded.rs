@@ -0,0 +1,18 @@
+use expect_macro_derive::Expect;
+enum Foo {
+    Bar(i32),
+}
+#[automatically_derived]
+impl Foo {
+    #[must_use]
+    pub fn expect_bar(self, value_0: i32) -> Option<(i32)>
+    where
+        for<'__eq> i32: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,8 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use expect_macro_derive::Expect;
+#[expect_macro(deref)]
+enum Name {
+    Name(String),
+}
+#[automatically_derived]
+impl Name {
+    #[must_use]
+    pub fn expect_name(self, value_0: String) -> Option<(String)>
+    where
+        for<'__eq> String: ::core::cmp::PartialEq,
+    {
+        match self {
+            Name::Name(attr_0) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::ops::Deref for Name {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Name::Name(attr_0) => attr_0,
+        }
+    }
+}
+fn main() {}
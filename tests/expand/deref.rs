@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(deref)]
+enum Name {
+    Name(String),
+}
+
+fn main() {}
@@ -0,0 +1,67 @@
+use expect_macro_derive::Expect;
+trait ApproxEq {
+    fn approx_eq(&self, other: &Self) -> bool;
+}
+enum Foo<T> {
+    Bar(T, u8),
+}
+#[automatically_derived]
+impl<T> Foo<T> {
+    #[must_use]
+    pub fn expect_bar(self, value_0: T, value_1: u8) -> Option<(T, u8)>
+    where
+        for<'__eq> T: ::core::cmp::PartialEq,
+        for<'__eq> u8: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0, attr_1) if attr_0 == value_0 && attr_1 == value_1 => {
+                Some((attr_0, attr_1))
+            }
+            _ => None,
+        }
+    }
+}
+#[expect_macro(eq_impl)]
+enum Baz<T> {
+    Bar(T, String),
+}
+#[automatically_derived]
+impl<T> Baz<T> {
+    #[must_use]
+    pub fn expect_bar<
+        __Eq0: ::core::cmp::PartialEq<T>,
+        __Eq1: ::core::cmp::PartialEq<String>,
+    >(self, value_0: __Eq0, value_1: __Eq1) -> Option<(T, String)> {
+        match self {
+            Baz::Bar(attr_0, attr_1) if value_0 == attr_0 && value_1 == attr_1 => {
+                Some((attr_0, attr_1))
+            }
+            _ => None,
+        }
+    }
+}
+#[expect_macro(eq_trait = "ApproxEq")]
+enum Qux<T> {
+    Bar(T, f64),
+}
+#[automatically_derived]
+impl<T> Qux<T> {
+    #[must_use]
+    pub fn expect_bar(self, value_0: T, value_1: f64) -> Option<(T, f64)>
+    where
+        for<'__eq> T: ApproxEq,
+        for<'__eq> f64: ApproxEq,
+    {
+        match self {
+            Qux::Bar(
+                attr_0,
+                attr_1,
+            ) if <T as ApproxEq>::approx_eq(&attr_0, &value_0)
+                && <f64 as ApproxEq>::approx_eq(&attr_1, &value_1) => {
+                Some((attr_0, attr_1))
+            }
+            _ => None,
+        }
+    }
+}
+fn main() {}
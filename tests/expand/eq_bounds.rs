@@ -0,0 +1,24 @@
+use expect_macro_derive::Expect;
+
+trait ApproxEq {
+    fn approx_eq(&self, other: &Self) -> bool;
+}
+
+#[derive(Expect)]
+enum Foo<T> {
+    Bar(T, u8),
+}
+
+#[derive(Expect)]
+#[expect_macro(eq_impl)]
+enum Baz<T> {
+    Bar(T, String),
+}
+
+#[derive(Expect)]
+#[expect_macro(eq_trait = "ApproxEq")]
+enum Qux<T> {
+    Bar(T, f64),
+}
+
+fn main() {}
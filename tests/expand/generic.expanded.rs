@@ -0,0 +1,51 @@
+use expect_macro_derive::Expect;
+#[expect_macro(as_ref, bound = "T: Clone")]
+enum Foo<'a, T: 'a, const N: usize>
+where
+    T: Default,
+{
+    Bar(&'a T),
+    Baz([T; N]),
+}
+#[automatically_derived]
+impl<'a, T: 'a, const N: usize> Foo<'a, T, N>
+where
+    T: Default,
+    T: Clone,
+{
+    #[must_use]
+    pub fn expect_bar(self, value_0: &'a T) -> Option<(&'a T)>
+    where
+        for<'__eq> &'a T: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn as_bar<'__expect>(&'__expect self) -> Option<(&'__expect &'a T)> {
+        match self {
+            Foo::Bar(attr_0) => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn expect_baz(self, value_0: [T; N]) -> Option<([T; N])>
+    where
+        for<'__eq> [T; N]: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Baz(attr_0) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn as_baz<'__expect>(&'__expect self) -> Option<(&'__expect [T; N])> {
+        match self {
+            Foo::Baz(attr_0) => Some((attr_0)),
+            _ => None,
+        }
+    }
+}
+fn main() {}
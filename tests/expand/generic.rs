@@ -0,0 +1,13 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(as_ref, bound = "T: Clone")]
+enum Foo<'a, T: 'a, const N: usize>
+where
+    T: Default,
+{
+    Bar(&'a T),
+    Baz([T; N]),
+}
+
+fn main() {}
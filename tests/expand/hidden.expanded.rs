@@ -0,0 +1,47 @@
+use expect_macro_derive::Expect;
+#[expect_macro(hidden, is, variant_count)]
+enum Foo {
+    Bar(i32),
+    Qux,
+}
+#[automatically_derived]
+impl Foo {
+    #[doc(hidden)]
+    pub const VARIANT_COUNT: usize = 2usize;
+    #[doc(hidden)]
+    #[must_use]
+    pub fn expect_bar(self, value_0: i32) -> Option<(i32)>
+    where
+        for<'__eq> i32: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[doc(hidden)]
+    pub fn is_bar(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Foo::Bar { .. } => true,
+            _ => false,
+        }
+    }
+    #[doc(hidden)]
+    #[must_use]
+    pub fn expect_qux(self) -> Option<()> {
+        match self {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+    #[doc(hidden)]
+    pub fn is_qux(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Foo::Qux { .. } => true,
+            _ => false,
+        }
+    }
+}
+fn main() {}
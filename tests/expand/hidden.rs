@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(hidden, is, variant_count)]
+enum Foo {
+    Bar(i32),
+    Qux,
+}
+
+fn main() {}
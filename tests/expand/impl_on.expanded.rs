@@ -0,0 +1,99 @@
+use expect_macro_derive::Expect;
+#[expect_macro(impl_on = "Wrapper<T>", as_ref, into)]
+enum Foo<T> {
+    Bar(T),
+    #[panic]
+    Baz { a: u8 },
+}
+#[automatically_derived]
+impl<T> Foo<T> {
+    #[must_use]
+    pub fn expect_bar(self, value_0: T) -> Option<(T)>
+    where
+        for<'__eq> T: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn as_bar<'__expect>(&'__expect self) -> Option<(&'__expect T)> {
+        match self {
+            Foo::Bar(attr_0) => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn into_bar(self) -> Option<(T)> {
+        match self {
+            Foo::Bar(attr_0) => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[track_caller]
+    pub fn expect_baz(self, a: u8) -> (u8)
+    where
+        for<'__eq> u8: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Baz { a: attr_a } if attr_a == a => (attr_a),
+            _ => {
+                ::std::rt::panic_fmt(
+                    format_args!(
+                        "expected {0:?}, found {1:?}", Self::Baz { a : a }, self
+                    ),
+                );
+            }
+        }
+    }
+    #[must_use]
+    pub fn as_baz<'__expect>(&'__expect self) -> Option<(&'__expect u8)> {
+        match self {
+            Foo::Baz { a: attr_a } => Some((attr_a)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn into_baz(self) -> Option<(u8)> {
+        match self {
+            Foo::Baz { a: attr_a } => Some((attr_a)),
+            _ => None,
+        }
+    }
+}
+#[automatically_derived]
+impl<T> Wrapper<T> {
+    #[must_use]
+    pub fn expect_bar(self, value_0: T) -> Option<(T)>
+    where
+        for<'__eq> T: ::core::cmp::PartialEq,
+    {
+        <Foo<T>>::expect_bar(::core::convert::Into::<Foo<T>>::into(self), value_0)
+    }
+    #[must_use]
+    pub fn as_bar<'__expect>(&'__expect self) -> Option<(&'__expect T)> {
+        <Foo<T>>::as_bar(self)
+    }
+    #[must_use]
+    pub fn into_bar(self) -> Option<(T)> {
+        <Foo<T>>::into_bar(::core::convert::Into::<Foo<T>>::into(self))
+    }
+    #[track_caller]
+    pub fn expect_baz(self, a: u8) -> (u8)
+    where
+        for<'__eq> u8: ::core::cmp::PartialEq,
+    {
+        <Foo<T>>::expect_baz(::core::convert::Into::<Foo<T>>::into(self), a)
+    }
+    #[must_use]
+    pub fn as_baz<'__expect>(&'__expect self) -> Option<(&'__expect u8)> {
+        <Foo<T>>::as_baz(self)
+    }
+    #[must_use]
+    pub fn into_baz(self) -> Option<(u8)> {
+        <Foo<T>>::into_baz(::core::convert::Into::<Foo<T>>::into(self))
+    }
+}
+struct Wrapper<T>(Foo<T>);
+fn main() {}
@@ -0,0 +1,13 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(impl_on = "Wrapper<T>", as_ref, into)]
+enum Foo<T> {
+    Bar(T),
+    #[panic]
+    Baz { a: u8 },
+}
+
+struct Wrapper<T>(Foo<T>);
+
+fn main() {}
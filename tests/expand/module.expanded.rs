@@ -0,0 +1,93 @@
+use expect_macro_derive::Expect;
+#[expect_macro(module = "foo_ext", into)]
+pub(crate) enum Foo {
+    Bar(i32),
+    Qux,
+}
+#[automatically_derived]
+impl Foo {}
+///The `FooExt` trait.
+pub(crate) mod foo_ext {
+    use super::*;
+    ///The methods generated by `Expect` for [`Foo`].
+    pub(crate) trait FooExt: ::core::marker::Sized {
+        #[must_use]
+        fn expect_bar(self, value_0: i32) -> Option<(i32)>
+        where
+            for<'__eq> i32: ::core::cmp::PartialEq;
+        #[must_use]
+        fn into_bar(self) -> Option<(i32)>;
+        #[must_use]
+        fn expect_qux(self) -> Option<()>;
+        #[must_use]
+        fn into_qux(self) -> Option<()>;
+    }
+    #[automatically_derived]
+    impl FooExt for Foo {
+        fn expect_bar(self, value_0: i32) -> Option<(i32)>
+        where
+            for<'__eq> i32: ::core::cmp::PartialEq,
+        {
+            match self {
+                Foo::Bar(attr_0) if attr_0 == value_0 => Some((attr_0)),
+                _ => None,
+            }
+        }
+        fn into_bar(self) -> Option<(i32)> {
+            match self {
+                Foo::Bar(attr_0) => Some((attr_0)),
+                _ => None,
+            }
+        }
+        fn expect_qux(self) -> Option<()> {
+            match self {
+                Foo::Qux => Some(()),
+                _ => None,
+            }
+        }
+        fn into_qux(self) -> Option<()> {
+            match self {
+                Foo::Qux => Some(()),
+                _ => None,
+            }
+        }
+    }
+}
+mod nested {
+    use expect_macro_derive::Expect;
+    #[expect_macro(module = "baz_ext", with_methods)]
+    pub(super) enum Baz {
+        Qux { a: u8 },
+    }
+    #[automatically_derived]
+    impl Baz {}
+    ///The `BazExt` trait.
+    pub(super) mod baz_ext {
+        use super::*;
+        ///The methods generated by `Expect` for [`Baz`].
+        pub(in super::super) trait BazExt: ::core::marker::Sized {
+            #[must_use]
+            fn expect_qux(self, a: u8) -> Option<(u8)>
+            where
+                for<'__eq> u8: ::core::cmp::PartialEq;
+            fn with_qux(self, a: u8) -> Self;
+        }
+        #[automatically_derived]
+        impl BazExt for Baz {
+            fn expect_qux(self, a: u8) -> Option<(u8)>
+            where
+                for<'__eq> u8: ::core::cmp::PartialEq,
+            {
+                match self {
+                    Baz::Qux { a: attr_a } if attr_a == a => Some((attr_a)),
+                    _ => None,
+                }
+            }
+            fn with_qux(mut self, a: u8) -> Self {
+                self = Self::Qux { a: a };
+                self
+            }
+        }
+    }
+}
+fn main() {}
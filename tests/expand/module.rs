@@ -0,0 +1,20 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(module = "foo_ext", into)]
+pub(crate) enum Foo {
+    Bar(i32),
+    Qux,
+}
+
+mod nested {
+    use expect_macro_derive::Expect;
+
+    #[derive(Expect)]
+    #[expect_macro(module = "baz_ext", with_methods)]
+    pub(super) enum Baz {
+        Qux { a: u8 },
+    }
+}
+
+fn main() {}
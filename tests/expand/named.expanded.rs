@@ -0,0 +1,35 @@
+use expect_macro_derive::Expect;
+#[expect_macro(as_ref, into)]
+enum Foo {
+    Bar { a: i32, #[ignore_eq] b: String },
+}
+#[automatically_derived]
+impl Foo {
+    #[must_use]
+    pub fn expect_bar(self, a: i32) -> Option<(i32, String)>
+    where
+        for<'__eq> i32: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar { a: attr_a, b: attr_b } if attr_a == a => Some((attr_a, attr_b)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn as_bar<'__expect>(
+        &'__expect self,
+    ) -> Option<(&'__expect i32, &'__expect String)> {
+        match self {
+            Foo::Bar { a: attr_a, b: attr_b } => Some((attr_a, attr_b)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn into_bar(self) -> Option<(i32, String)> {
+        match self {
+            Foo::Bar { a: attr_a, b: attr_b } => Some((attr_a, attr_b)),
+            _ => None,
+        }
+    }
+}
+fn main() {}
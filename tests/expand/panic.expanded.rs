@@ -0,0 +1,74 @@
+use expect_macro_derive::Expect;
+enum Foo {
+    #[panic]
+    Bar { a: i32 },
+    #[panic]
+    Baz(i32, #[ignore_eq] i32),
+    Qux,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for Foo {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Foo::Bar { a: __self_0 } => {
+                ::core::fmt::Formatter::debug_struct_field1_finish(
+                    f,
+                    "Bar",
+                    "a",
+                    &__self_0,
+                )
+            }
+            Foo::Baz(__self_0, __self_1) => {
+                ::core::fmt::Formatter::debug_tuple_field2_finish(
+                    f,
+                    "Baz",
+                    __self_0,
+                    &__self_1,
+                )
+            }
+            Foo::Qux => ::core::fmt::Formatter::write_str(f, "Qux"),
+        }
+    }
+}
+#[automatically_derived]
+impl Foo {
+    #[track_caller]
+    pub fn expect_bar(self, a: i32) -> (i32)
+    where
+        for<'__eq> i32: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar { a: attr_a } if attr_a == a => (attr_a),
+            _ => {
+                ::std::rt::panic_fmt(
+                    format_args!(
+                        "expected {0:?}, found {1:?}", Self::Bar { a : a }, self
+                    ),
+                );
+            }
+        }
+    }
+    #[track_caller]
+    pub fn expect_baz(self, value_0: i32) -> (i32, i32)
+    where
+        for<'__eq> i32: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Baz(attr_0, attr_1) if attr_0 == value_0 => (attr_0, attr_1),
+            _ => {
+                ::std::rt::panic_fmt(
+                    format_args!("expected Foo::Baz, found {0:?}", self),
+                );
+            }
+        }
+    }
+    #[must_use]
+    pub fn expect_qux(self) -> Option<()> {
+        match self {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,12 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+enum Foo {
+    #[panic]
+    Bar { a: i32 },
+    #[panic]
+    Baz(i32, #[ignore_eq] i32),
+    Qux,
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+use std::marker::PhantomPinned;
+use expect_macro_derive::Expect;
+#[expect_macro(pin_projections)]
+enum Foo<T> {
+    Bar(T, #[phantom] PhantomPinned),
+    Qux,
+}
+#[automatically_derived]
+impl<T> Foo<T> {
+    #[must_use]
+    pub fn expect_bar(self, value_0: T) -> Option<(T)>
+    where
+        for<'__eq> T: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0, _) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn as_bar_pin<'__expect>(
+        self: ::core::pin::Pin<&'__expect mut Self>,
+    ) -> Option<(::core::pin::Pin<&'__expect mut T>)> {
+        match unsafe { ::core::pin::Pin::get_unchecked_mut(self) } {
+            Foo::Bar(attr_0, _) => {
+                Some((unsafe { ::core::pin::Pin::new_unchecked(attr_0) }))
+            }
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn expect_qux(self) -> Option<()> {
+        match self {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn as_qux_pin<'__expect>(
+        self: ::core::pin::Pin<&'__expect mut Self>,
+    ) -> Option<()> {
+        match unsafe { ::core::pin::Pin::get_unchecked_mut(self) } {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+}
+const _: () = {
+    trait __ExpectMustNotImplDrop {}
+    #[allow(drop_bounds)]
+    impl<T: ::core::ops::Drop> __ExpectMustNotImplDrop for T {}
+    impl<T> __ExpectMustNotImplDrop for Foo<T> {}
+};
+#[automatically_derived]
+impl<T> ::core::marker::Unpin for Foo<T>
+where
+    for<'__pin> T: ::core::marker::Unpin,
+    for<'__pin> PhantomPinned: ::core::marker::Unpin,
+{}
+fn main() {}
@@ -0,0 +1,12 @@
+use std::marker::PhantomPinned;
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(pin_projections)]
+enum Foo<T> {
+    Bar(T, #[phantom] PhantomPinned),
+    Qux,
+}
+
+fn main() {}
@@ -0,0 +1,118 @@
+use expect_macro_derive::Expect;
+#[expect_macro(ref_enum, projection)]
+enum Foo<T> {
+    Bar(T),
+    Baz { a: String },
+    Qux,
+}
+#[automatically_derived]
+impl<T> Foo<T> {
+    #[must_use]
+    pub fn expect_bar(self, value_0: T) -> Option<(T)>
+    where
+        for<'__eq> T: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0) if attr_0 == value_0 => Some((attr_0)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn expect_baz(self, a: String) -> Option<(String)>
+    where
+        for<'__eq> String: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Baz { a: attr_a } if attr_a == a => Some((attr_a)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn expect_qux(self) -> Option<()> {
+        match self {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+    pub fn as_ref_enum<'__expect>(&'__expect self) -> FooRef<'__expect, T> {
+        match self {
+            Foo::Bar(attr_0) => FooRef::Bar(attr_0),
+            Foo::Baz { a: attr_a } => FooRef::Baz { a: attr_a },
+            Foo::Qux => FooRef::Qux,
+        }
+    }
+    pub fn project<'__expect>(&'__expect self) -> FooProjection<'__expect, T> {
+        match self {
+            Foo::Bar(attr_0) => FooProjection::Bar(attr_0),
+            Foo::Baz { a: attr_a } => FooProjection::Baz { a: attr_a },
+            Foo::Qux => FooProjection::Qux,
+        }
+    }
+}
+///A borrowed view of a [`Foo`], built by `as_ref_enum`.
+enum FooRef<'__expect, T> {
+    Bar(&'__expect T),
+    Baz { a: &'__expect String },
+    Qux,
+}
+#[automatically_derived]
+impl<'__expect, T> ::core::clone::Clone for FooRef<'__expect, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl<'__expect, T> ::core::marker::Copy for FooRef<'__expect, T> {}
+#[automatically_derived]
+impl<'__expect, T> FooRef<'__expect, T> {
+    /// Clones the borrowed fields back into an owned value.
+    pub fn to_owned(&self) -> Foo<T>
+    where
+        for<'__clone> T: ::core::clone::Clone,
+        for<'__clone> String: ::core::clone::Clone,
+    {
+        match *self {
+            FooRef::Bar(attr_0) => Foo::Bar(::core::clone::Clone::clone(attr_0)),
+            FooRef::Baz { a: attr_a } => {
+                Foo::Baz {
+                    a: ::core::clone::Clone::clone(attr_a),
+                }
+            }
+            FooRef::Qux => Foo::Qux,
+        }
+    }
+}
+///A borrowed view of a [`Foo`], built by `project`.
+enum FooProjection<'__expect, T> {
+    Bar(&'__expect T),
+    Baz { a: &'__expect String },
+    Qux,
+}
+#[automatically_derived]
+impl<'__expect, T> ::core::clone::Clone for FooProjection<'__expect, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl<'__expect, T> ::core::marker::Copy for FooProjection<'__expect, T> {}
+#[automatically_derived]
+impl<'__expect, T> FooProjection<'__expect, T> {
+    /// Clones the borrowed fields back into an owned value.
+    pub fn to_owned(&self) -> Foo<T>
+    where
+        for<'__clone> T: ::core::clone::Clone,
+        for<'__clone> String: ::core::clone::Clone,
+    {
+        match *self {
+            FooProjection::Bar(attr_0) => Foo::Bar(::core::clone::Clone::clone(attr_0)),
+            FooProjection::Baz { a: attr_a } => {
+                Foo::Baz {
+                    a: ::core::clone::Clone::clone(attr_a),
+                }
+            }
+            FooProjection::Qux => Foo::Qux,
+        }
+    }
+}
+fn main() {}
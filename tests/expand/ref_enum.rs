@@ -0,0 +1,11 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(ref_enum, projection)]
+enum Foo<T> {
+    Bar(T),
+    Baz { a: String },
+    Qux,
+}
+
+fn main() {}
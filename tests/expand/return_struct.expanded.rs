@@ -0,0 +1,88 @@
+use expect_macro_derive::Expect;
+#[expect_macro(into)]
+enum Foo<T> {
+    #[return_struct(derive(Debug))]
+    Bar { a: T, b: u8 },
+    #[return_struct(name = "Pair")]
+    Baz(u8, u8),
+    Qux,
+}
+#[automatically_derived]
+impl<T> Foo<T> {
+    #[must_use]
+    pub fn expect_bar(self, a: T, b: u8) -> Option<FooBar<T>>
+    where
+        for<'__eq> T: ::core::cmp::PartialEq,
+        for<'__eq> u8: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar { a: attr_a, b: attr_b } if attr_a == a && attr_b == b => {
+                Some(FooBar { a: attr_a, b: attr_b })
+            }
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn into_bar(self) -> Option<FooBar<T>> {
+        match self {
+            Foo::Bar { a: attr_a, b: attr_b } => Some(FooBar { a: attr_a, b: attr_b }),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn expect_baz(self, value_0: u8, value_1: u8) -> Option<Pair<T>>
+    where
+        for<'__eq> u8: ::core::cmp::PartialEq,
+        for<'__eq> u8: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Baz(attr_0, attr_1) if attr_0 == value_0 && attr_1 == value_1 => {
+                Some(Pair(attr_0, attr_1))
+            }
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn into_baz(self) -> Option<Pair<T>> {
+        match self {
+            Foo::Baz(attr_0, attr_1) => Some(Pair(attr_0, attr_1)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn expect_qux(self) -> Option<()> {
+        match self {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn into_qux(self) -> Option<()> {
+        match self {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+}
+///the fields of `Foo::Bar`.
+struct FooBar<T> {
+    pub a: T,
+    pub b: u8,
+}
+#[automatically_derived]
+impl<T: ::core::fmt::Debug> ::core::fmt::Debug for FooBar<T> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "FooBar",
+            "a",
+            &self.a,
+            "b",
+            &&self.b,
+        )
+    }
+}
+///the fields of `Foo::Baz`.
+struct Pair<T>(pub u8, pub u8);
+fn main() {}
@@ -0,0 +1,13 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(into)]
+enum Foo<T> {
+    #[return_struct(derive(Debug))]
+    Bar { a: T, b: u8 },
+    #[return_struct(name = "Pair")]
+    Baz(u8, u8),
+    Qux,
+}
+
+fn main() {}
@@ -0,0 +1,78 @@
+use expect_macro_derive::Expect;
+#[expect_macro(is, assertions)]
+enum Foo {
+    Bar,
+    Qux,
+}
+#[automatically_derived]
+impl Foo {
+    #[must_use]
+    pub fn expect_bar(self) -> Option<()> {
+        match self {
+            Foo::Bar => Some(()),
+            _ => None,
+        }
+    }
+    #[track_caller]
+    pub fn assert_bar(&self) {
+        if !#[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Foo::Bar { .. } => true,
+            _ => false,
+        } {
+            {
+                ::std::rt::panic_fmt(
+                    format_args!(
+                        "assertion failed: expected `Foo::Bar`, found `Foo::{0}`", self
+                        .__expect_variant_name(),
+                    ),
+                );
+            };
+        }
+    }
+    pub fn is_bar(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Foo::Bar { .. } => true,
+            _ => false,
+        }
+    }
+    #[must_use]
+    pub fn expect_qux(self) -> Option<()> {
+        match self {
+            Foo::Qux => Some(()),
+            _ => None,
+        }
+    }
+    #[track_caller]
+    pub fn assert_qux(&self) {
+        if !#[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Foo::Qux { .. } => true,
+            _ => false,
+        } {
+            {
+                ::std::rt::panic_fmt(
+                    format_args!(
+                        "assertion failed: expected `Foo::Qux`, found `Foo::{0}`", self
+                        .__expect_variant_name(),
+                    ),
+                );
+            };
+        }
+    }
+    pub fn is_qux(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Foo::Qux { .. } => true,
+            _ => false,
+        }
+    }
+    fn __expect_variant_name(&self) -> &'static str {
+        match *self {
+            Foo::Bar { .. } => "Bar",
+            Foo::Qux { .. } => "Qux",
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(is, assertions)]
+enum Foo {
+    Bar,
+    Qux,
+}
+
+fn main() {}
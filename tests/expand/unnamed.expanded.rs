@@ -0,0 +1,38 @@
+use expect_macro_derive::Expect;
+#[expect_macro(as_ref, into)]
+enum Foo {
+    Bar(i32, String),
+}
+#[automatically_derived]
+impl Foo {
+    #[must_use]
+    pub fn expect_bar(self, value_0: i32, value_1: String) -> Option<(i32, String)>
+    where
+        for<'__eq> i32: ::core::cmp::PartialEq,
+        for<'__eq> String: ::core::cmp::PartialEq,
+    {
+        match self {
+            Foo::Bar(attr_0, attr_1) if attr_0 == value_0 && attr_1 == value_1 => {
+                Some((attr_0, attr_1))
+            }
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn as_bar<'__expect>(
+        &'__expect self,
+    ) -> Option<(&'__expect i32, &'__expect String)> {
+        match self {
+            Foo::Bar(attr_0, attr_1) => Some((attr_0, attr_1)),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn into_bar(self) -> Option<(i32, String)> {
+        match self {
+            Foo::Bar(attr_0, attr_1) => Some((attr_0, attr_1)),
+            _ => None,
+        }
+    }
+}
+fn main() {}
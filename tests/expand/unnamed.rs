@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(as_ref, into)]
+enum Foo {
+    Bar(i32, String),
+}
+
+fn main() {}
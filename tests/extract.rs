@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use expect_macro_derive::Expect;
+
+// doesn't implement PartialEq, Debug or Clone.
+struct Body;
+
+#[derive(Expect)]
+enum Message {
+    #[extract(url)]
+    Request { url: String, method: String, headers: HashMap<String, String>, body: Body },
+    #[extract(code, reason)]
+    Response { reason: String, code: u16, body: Body },
+}
+
+#[test]
+fn extracts_a_single_field() {
+    let request = Message::Request { url: "/".to_string(), method: "GET".to_string(), headers: HashMap::new(), body: Body };
+    assert_eq!(request.expect_request_partial(), Some("/".to_string()));
+}
+
+#[test]
+fn extracts_fields_in_the_given_order() {
+    let response = Message::Response { reason: "OK".to_string(), code: 200, body: Body };
+    assert_eq!(response.expect_response_partial(), Some((200, "OK".to_string())));
+
+    let response = Message::Response { reason: "OK".to_string(), code: 200, body: Body };
+    assert_eq!(response.expect_request_partial(), None);
+}
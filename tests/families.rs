@@ -0,0 +1,99 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(all)]
+enum Token {
+    Number { value: i64 },
+    Ident(String),
+    Eof,
+}
+
+#[test]
+fn all_enables_every_family() {
+    let mut token = Token::Number { value: 1 };
+    assert!(token.is_number());
+    assert!(!token.is_eof());
+    assert_eq!(token.as_number(), Some(&1));
+    *token.as_mut_number().unwrap() = 2;
+    assert_eq!(token.get_number_value(), Some(&2));
+    assert_eq!(token.expect_number_cloned(2), Some(2));
+    token.assert_number();
+    assert_eq!(token.clone().try_get_ident(), Err("Number"));
+    assert_eq!(token.clone().into_number(), Some(2));
+    assert_eq!(token.unwrap_number(), 2);
+}
+
+#[test]
+#[should_panic(expected = "expected Token::Ident, found Eof")]
+fn unwrap_panics_on_other_variant() {
+    Token::Eof.unwrap_ident();
+}
+
+#[derive(Expect)]
+#[expect_macro(only(is, as_ref))]
+enum Shape {
+    Circle(f64),
+    Square(f64),
+}
+
+// the families that are not listed are not generated.
+trait IntoCircle {
+    fn into_circle(self) -> Option<f64>;
+}
+
+impl IntoCircle for Shape {
+    fn into_circle(self) -> Option<f64> {
+        None
+    }
+}
+
+#[test]
+fn only_enables_the_listed_families() {
+    let circle = Shape::Circle(1.0);
+    assert!(circle.is_circle());
+    assert_eq!(circle.as_circle(), Some(&1.0));
+    assert_eq!(circle.as_square(), None);
+    assert_eq!(circle.into_circle(), None);
+}
+
+#[test]
+fn all_enables_the_later_families() {
+    let mut token = Token::Number { value: 1 };
+    assert_eq!(token.set_number_value(2), Some(1));
+    assert_eq!(token.try_as_number(), Ok(&2));
+    assert_eq!(token.clone().expect_number_or_self(3), Err(Token::Number { value: 2 }));
+    assert_eq!(token.clone().with_ident("a".to_string()), Token::Ident("a".to_string()));
+    assert_eq!(token.take_number(), Some(2));
+    assert_eq!(token, Token::Eof);
+}
+
+// without a unit variant to leave in place, `all` doesn't generate `take`.
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(all)]
+enum Pair {
+    Left(u8),
+    Right(u8),
+}
+
+#[test]
+fn all_without_a_unit_variant() {
+    assert_eq!(Pair::Left(1).try_as_left(), Ok(&1));
+    assert_eq!(Pair::Left(1).with_right(2), Pair::Right(2));
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(only(setters, take))]
+enum Job {
+    Queued { id: u32 },
+    Idle,
+}
+
+#[test]
+fn only_with_the_later_families() {
+    let mut job = Job::Queued { id: 1 };
+    assert_eq!(job.set_queued_id(2), Some(1));
+    assert_eq!(job.take_queued(), Some(2));
+    assert!(matches!(job, Job::Idle));
+}
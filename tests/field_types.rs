@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use expect_macro_derive::Expect;
+
+type Nested = HashMap<String, Option<Vec<(u8, String)>>>;
+
+#[derive(Debug, Expect)]
+#[expect_macro(as_ref)]
+enum Config {
+    #[panic]
+    Table(std::collections::HashMap<String, Vec<u8>>),
+    Nested { map: HashMap<String, Option<Vec<(u8, String)>>>, depth: usize },
+}
+
+#[test]
+fn fully_qualified_field_type() {
+    let mut map = HashMap::new();
+    map.insert("a".to_string(), vec![1, 2]);
+
+    let table = Config::Table(map.clone());
+    assert_eq!(table.as_table(), Some(&map));
+
+    let extracted: HashMap<String, Vec<u8>> = table.expect_table(map.clone());
+    assert_eq!(extracted, map);
+}
+
+#[test]
+fn nested_generic_field_type() {
+    let mut map = HashMap::new();
+    map.insert("a".to_string(), Some(vec![(1, "b".to_string())]));
+
+    let nested = Config::Nested { map: map.clone(), depth: 2 };
+    let extracted: Option<(Nested, usize)> = nested.expect_nested(map.clone(), 2);
+    assert_eq!(extracted, Some((map, 2)));
+}
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(into, as_ref, ref_enum)]
+enum List {
+    #[panic]
+    Cons(i32, Box<Self>),
+    Nil,
+}
+
+#[test]
+#[allow(clippy::borrowed_box)]
+fn self_in_field_types() {
+    let list = List::Cons(1, Box::new(List::Cons(2, Box::new(List::Nil))));
+
+    let (head, tail): (&i32, &Box<List>) = list.as_cons().unwrap();
+    assert_eq!(*head, 1);
+    assert_eq!(tail.as_cons(), Some((&2, &Box::new(List::Nil))));
+
+    match list.as_ref_enum() {
+        ListRef::Cons(head, tail) => {
+            assert_eq!(*head, 1);
+            assert_eq!(tail.as_ref_enum().to_owned(), List::Cons(2, Box::new(List::Nil)));
+        },
+        ListRef::Nil => panic!("expected Cons"),
+    }
+
+    let (head, tail) = list.clone().expect_cons(1, Box::new(List::Cons(2, Box::new(List::Nil))));
+    assert_eq!(head, 1);
+    assert_eq!(tail.into_cons(), Some((2, Box::new(List::Nil))));
+    assert_eq!(list.into_nil(), None);
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(future_accessors)]
+enum Step {
+    Pending(Pin<Box<dyn Future<Output = u32>>>),
+    Ready(Ready<String>),
+    Value(u32),
+    Done,
+}
+
+/// polls `future` to completion, for the futures which don't need a runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    let waker = unsafe { Waker::from_raw(clone(std::ptr::null())) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn await_the_extracted_future() {
+    let step = Step::Pending(Box::pin(async { 1 + 2 }));
+    let output = block_on(async { step.into_pending_future().unwrap().await });
+    assert_eq!(output, 3);
+    assert_eq!(block_on(Step::Ready(ready("a".to_string())).into_ready_future().unwrap()), "a");
+}
+
+#[test]
+fn other_variant() {
+    assert!(Step::Done.into_pending_future().is_none());
+    assert!(Step::Value(1).into_ready_future().is_none());
+}
@@ -0,0 +1,87 @@
+use std::marker::PhantomData;
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+enum Buffer<const N: usize> {
+    Full([u8; N]),
+    Empty,
+}
+
+#[derive(Debug, Expect)]
+enum Wrapper<T: PartialEq> {
+    Value(T),
+    Nothing,
+}
+
+// `T` is only used through `PhantomData`, and doesn't implement anything.
+#[derive(Expect)]
+#[expect_macro(assertions, as_ref, into, try_get)]
+enum Tagged<T> {
+    A,
+    B,
+    #[doc(hidden)]
+    Marker(PhantomData<T>),
+}
+
+struct Tag;
+
+#[test]
+fn const_generic_enum() {
+    let full: Buffer<3> = Buffer::Full([1, 2, 3]);
+    assert_eq!(full.expect_full([1, 2, 3]), Some([1, 2, 3]));
+
+    let full: Buffer<3> = Buffer::Full([1, 2, 3]);
+    assert_eq!(full.expect_full([3, 2, 1]), None);
+
+    let empty: Buffer<3> = Buffer::Empty;
+    assert_eq!(empty.expect_empty(), Some(()));
+}
+
+#[test]
+fn type_generic_enum() {
+    assert_eq!(Wrapper::Value("a").expect_value("a"), Some("a"));
+    assert_eq!(Wrapper::<&str>::Nothing.expect_value("a"), None);
+}
+
+#[test]
+fn phantom_type_parameter() {
+    let a: Tagged<Tag> = Tagged::A;
+    a.assert_a();
+    assert_eq!(a.as_a(), Some(()));
+    assert_eq!(a.as_b(), None);
+    assert_eq!(a.expect_a(), Some(()));
+    assert_eq!(Tagged::<Tag>::B.try_get_a(), Err("B"));
+    assert_eq!(Tagged::<Tag>::B.into_b(), Some(()));
+    assert!(Tagged::<Tag>::Marker(PhantomData).into_marker().is_some());
+}
+
+// the lifetime bound of the where clause needs to be kept on the impl.
+#[derive(Expect)]
+#[expect_macro(as_ref, into)]
+enum Borrowed<'a, T>
+where
+    T: 'a,
+{
+    Ref(&'a T),
+    None,
+}
+
+#[test]
+fn lifetime_bound_in_where_clause() {
+    let value = String::from("a");
+    let borrowed = Borrowed::Ref(&value);
+    let inner: &&String = borrowed.as_ref().unwrap();
+    assert_eq!(*inner, "a");
+    let inner: &String = borrowed.into_ref().unwrap();
+    assert!(Borrowed::<String>::None.into_ref().is_none());
+    assert_eq!(Borrowed::Ref(inner).expect_ref(&value), Some(inner));
+}
+
+struct NotComparable;
+
+#[test]
+fn guard_bounds_only_checked_on_use() {
+    let value = NotComparable;
+    assert!(Borrowed::Ref(&value).as_ref().is_some());
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(getters)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect { #[rename = "w"] width_in_pixels: u32, #[rename = "h"] height_in_pixels: u32 },
+    Point(i32, i32),
+}
+
+#[test]
+fn field_getters() {
+    let circle = Shape::Circle { radius: 1.5 };
+    assert_eq!(circle.get_circle_radius(), Some(&1.5));
+    assert_eq!(circle.get_rect_w(), None);
+    assert_eq!(Shape::Point(1, 2).get_circle_radius(), None);
+}
+
+#[test]
+fn renamed_field_getters() {
+    let rect = Shape::Rect { width_in_pixels: 10, height_in_pixels: 20 };
+    assert_eq!(rect.get_rect_w(), Some(&10));
+    assert_eq!(rect.get_rect_h(), Some(&20));
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Connection {
+    #[expect_macro(group = "open")]
+    Connecting { attempt: u32 },
+    #[expect_macro(group = "open", group = "usable")]
+    Connected(String),
+    #[expect_macro(group = "usable")]
+    Cached(String),
+    Closed,
+}
+
+#[test]
+fn is_group() {
+    assert!(Connection::Connecting { attempt: 1 }.is_open());
+    assert!(Connection::Connected("a".to_string()).is_open());
+    assert!(!Connection::Closed.is_open());
+}
+
+#[test]
+fn variant_in_several_groups() {
+    assert!(Connection::Connected("a".to_string()).is_usable());
+    assert!(Connection::Cached("a".to_string()).is_usable());
+    assert!(!Connection::Connecting { attempt: 1 }.is_usable());
+}
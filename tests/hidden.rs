@@ -0,0 +1,36 @@
+//! the generated methods are not documented, `missing_docs` fails unless they are hidden.
+#![deny(missing_docs)]
+
+use expect_macro_derive::Expect;
+
+/// an enum.
+#[derive(Expect)]
+#[expect_macro(as_ref, hidden)]
+pub enum Foo {
+    /// a variant.
+    Bar(i32),
+}
+
+#[test]
+fn hidden() {
+    assert_eq!(Foo::Bar(1).as_bar(), Some(&1));
+}
+
+mod with_module {
+    use expect_macro_derive::Expect;
+
+    /// an enum with its methods in a trait.
+    #[derive(Expect)]
+    #[expect_macro(module = "foo_ext", hidden)]
+    pub enum Foo {
+        /// a variant.
+        Bar(i32),
+    }
+}
+
+#[test]
+fn hidden_in_trait() {
+    use with_module::foo_ext::FooExt;
+
+    assert_eq!(with_module::Foo::Bar(1).expect_bar(1), Some(1));
+}
@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(as_ref, into, cloned_accessors, getters)]
+enum Foo {
+    // `attr_a` is the name `a` is bound to in the generated patterns.
+    Bar { a: i32, attr_a: i32 },
+    Baz { attr_0: String, value_0: String },
+    #[panic]
+    Qux(i32, i32),
+}
+
+#[test]
+fn fields_named_like_the_bindings() {
+    let bar = Foo::Bar { a: 1, attr_a: 2 };
+    assert_eq!(bar.expect_bar_cloned(1, 2), Some((1, 2)));
+    assert_eq!(bar.expect_bar_cloned(2, 1), None);
+    assert_eq!(bar.get_bar_attr_a(), Some(&2));
+    assert_eq!(bar.expect_bar(1, 2), Some((1, 2)));
+}
+
+#[test]
+fn fields_named_like_the_tuple_arguments() {
+    let baz = Foo::Baz { attr_0: "a".to_string(), value_0: "b".to_string() };
+    assert_eq!(baz.as_baz(), Some((&"a".to_string(), &"b".to_string())));
+    assert_eq!(baz.expect_baz("a".to_string(), "b".to_string()), Some(("a".to_string(), "b".to_string())));
+    assert_eq!(Foo::Qux(1, 2).expect_qux(1, 2), (1, 2));
+}
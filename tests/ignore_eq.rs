@@ -0,0 +1,25 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+enum Foo {
+    Bar { a: i32, #[ignore_eq] b: String },
+    #[panic]
+    Baz(#[ignore_eq] i32, i32),
+}
+
+#[test]
+fn ignored_field_is_returned_but_not_compared() {
+    let bar = Foo::Bar { a: 1, b: "b".to_string() };
+    assert_eq!(bar.expect_bar(1), Some((1, "b".to_string())));
+
+    let bar = Foo::Bar { a: 1, b: "b".to_string() };
+    assert_eq!(bar.expect_bar(2), None);
+
+    assert_eq!(Foo::Baz(1, 2).expect_baz(2), (1, 2));
+}
+
+#[test]
+#[should_panic(expected = "expected Foo::Baz, found Baz(1, 2)")]
+fn ignored_field_panic_message() {
+    Foo::Baz(1, 2).expect_baz(3);
+}
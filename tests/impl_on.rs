@@ -0,0 +1,95 @@
+#![allow(dead_code)]
+
+use std::ops::{Deref, DerefMut};
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(impl_on = "State", as_ref, as_mut, is, into, discriminant_eq)]
+enum Inner {
+    #[expect_macro(construct)]
+    Running { pid: u32 },
+    #[panic]
+    Stopped(i32),
+}
+
+#[repr(transparent)]
+struct State(Inner);
+
+impl Deref for State {
+    type Target = Inner;
+
+    fn deref(&self) -> &Inner {
+        &self.0
+    }
+}
+
+impl DerefMut for State {
+    fn deref_mut(&mut self) -> &mut Inner {
+        &mut self.0
+    }
+}
+
+impl From<State> for Inner {
+    fn from(state: State) -> Inner {
+        state.0
+    }
+}
+
+#[test]
+fn borrowing_methods_deref_into_the_enum() {
+    let mut state = State(Inner::Running { pid: 1 });
+    assert!(state.is_running());
+    assert_eq!(state.as_running(), Some(&1));
+    *state.as_mut_running().unwrap() = 2;
+    assert_eq!(state.as_running(), Some(&2));
+    assert!(state.eq_variant(&Inner::Running { pid: 3 }));
+}
+
+#[test]
+fn consuming_methods_convert_into_the_enum() {
+    assert_eq!(State(Inner::Running { pid: 1 }).into_running(), Some(1));
+    assert_eq!(State(Inner::Running { pid: 1 }).expect_running(1), Some(1));
+    assert_eq!(State(Inner::Stopped(0)).expect_stopped(0), 0);
+}
+
+#[test]
+fn enum_keeps_its_methods() {
+    assert_eq!(Inner::Running { pid: 1 }.into_running(), Some(1));
+}
+
+#[test]
+fn constructors_are_forwarded() {
+    assert_eq!(State::running(1), Inner::Running { pid: 1 });
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(impl_on = "Slot<T>", as_ref, into)]
+enum Value<T> {
+    Some(T),
+    Missing,
+}
+
+struct Slot<T>(Value<T>);
+
+impl<T> Deref for Slot<T> {
+    type Target = Value<T>;
+
+    fn deref(&self) -> &Value<T> {
+        &self.0
+    }
+}
+
+impl<T> From<Slot<T>> for Value<T> {
+    fn from(slot: Slot<T>) -> Value<T> {
+        slot.0
+    }
+}
+
+#[test]
+fn generic_wrapper_of_a_generic_enum() {
+    let slot = Slot(Value::Some("a"));
+    assert_eq!(slot.as_some(), Some(&"a"));
+    assert_eq!(slot.into_some(), Some("a"));
+    assert!(Slot(Value::<u8>::Missing).into_some().is_none());
+}
@@ -0,0 +1,17 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(into)]
+enum Foo {
+    Bar { a: i32, b: String },
+    Baz(i32),
+    Qux,
+}
+
+#[test]
+fn into_extracts_without_comparing() {
+    assert_eq!(Foo::Bar { a: 1, b: "b".to_string() }.into_bar(), Some((1, "b".to_string())));
+    assert_eq!(Foo::Baz(2).into_baz(), Some(2));
+    assert_eq!(Foo::Baz(2).into_bar(), None);
+    assert_eq!(Foo::Qux.into_qux(), Some(()));
+}
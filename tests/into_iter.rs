@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use std::collections::BTreeSet;
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(into_iter)]
+enum Bag {
+    #[iter]
+    Items(Vec<String>),
+    Tagged {
+        #[iter]
+        tags: BTreeSet<String>,
+        owner: String,
+    },
+    Empty,
+}
+
+#[test]
+fn drains_the_vec() {
+    let items = Bag::Items(vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(items.into_iter_items().unwrap().collect::<Vec<String>>(), ["a", "b"]);
+    assert!(Bag::Empty.into_iter_items().is_none());
+}
+
+#[test]
+fn named_field_in_a_multi_field_variant() {
+    let tagged = Bag::Tagged { tags: ["y", "x"].iter().map(|tag| tag.to_string()).collect(), owner: "o".to_string() };
+    assert_eq!(tagged.iter_tagged_tags().unwrap().count(), 2);
+    assert_eq!(tagged.into_iter_tagged_tags().unwrap().collect::<Vec<String>>(), ["x", "y"]);
+}
@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(result_ok_variant = "Done")]
+enum Outcome {
+    Done(u32),
+    Failed { code: i32, reason: String },
+}
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(result_ok_variant = "Parsed")]
+enum Parse {
+    Parsed { key: String, value: i64 },
+    Empty,
+    Invalid(String),
+}
+
+#[test]
+fn two_variants() {
+    assert_eq!(Outcome::Done(3).into_result(), Ok(3));
+    let failed = Outcome::Failed { code: 1, reason: "no".to_string() };
+    assert_eq!(failed.into_result(), Err(Outcome::Failed { code: 1, reason: "no".to_string() }));
+}
+
+#[test]
+fn several_error_variants() {
+    let parsed = Parse::Parsed { key: "a".to_string(), value: 1 };
+    assert_eq!(parsed.into_result(), Ok(("a".to_string(), 1)));
+    assert_eq!(Parse::Empty.into_result(), Err(Parse::Empty));
+    assert_eq!(Parse::Invalid("x".to_string()).into_result().map_err(|err| err.expect_invalid("x".to_string())), Err(Some("x".to_string())));
+}
@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+use std::collections::BTreeMap;
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Inventory {
+    #[iter]
+    Items(Vec<String>),
+    Shelves { #[iter] labels: Vec<&'static str>, #[iter] counts: BTreeMap<&'static str, u32> },
+    Empty,
+}
+
+#[test]
+fn iterates_over_vec() {
+    let items = Inventory::Items(vec!["a".to_string(), "b".to_string()]);
+    let collected: Vec<&String> = items.iter_items().unwrap().collect();
+    assert_eq!(collected, ["a", "b"]);
+    assert!(Inventory::Empty.iter_items().is_none());
+}
+
+#[test]
+fn iterates_over_several_fields() {
+    let mut counts = BTreeMap::new();
+    counts.insert("x", 1);
+    let shelves = Inventory::Shelves { labels: vec!["top"], counts };
+    assert_eq!(shelves.iter_shelves_labels().unwrap().count(), 1);
+    assert_eq!(shelves.iter_shelves_counts().unwrap().map(|(_, count)| count).sum::<u32>(), 1);
+    assert!(shelves.iter_items().is_none());
+}
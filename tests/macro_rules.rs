@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+/// an enum whose name, variants and fields all come from the macro input.
+macro_rules! tokens {
+    ($name:ident { $($variant:ident($($ty:ty),*)),* $(,)? } { $($named:ident { $($field:ident: $field_ty:ty),* }),* $(,)? }) => {
+        #[derive(Debug, PartialEq, Expect)]
+        #[expect_macro(as_ref, is, getters, try_get)]
+        enum $name {
+            $($variant($($ty),*),)*
+            $($named { $($field: $field_ty),* },)*
+        }
+    };
+}
+
+tokens! {
+    Token { Number(i64), Pair(u8, char), Eof() }
+    { Ident { name: String, raw: bool } }
+}
+
+/// an enum written inside the macro, with a variant added by the caller.
+macro_rules! with_variant {
+    ($variant:ident) => {
+        #[derive(Debug, PartialEq, Expect)]
+        #[expect_macro(into)]
+        enum Level {
+            Low(u8),
+            $variant { value: u8 },
+        }
+
+        fn level(value: u8) -> Level {
+            Level::$variant { value }
+        }
+    };
+}
+
+with_variant!(High);
+
+#[test]
+fn variants_from_the_macro_input() {
+    assert_eq!(Token::Number(1).expect_number(1), Some(1));
+    assert_eq!(Token::Pair(1, 'a').as_pair(), Some((&1, &'a')));
+    assert!(Token::Eof().is_eof());
+    let ident = Token::Ident { name: "a".to_string(), raw: false };
+    assert_eq!(ident.get_ident_name(), Some(&"a".to_string()));
+    assert_eq!(ident.expect_ident("a".to_string(), false), Some(("a".to_string(), false)));
+    assert!(Token::Number(1).try_get_eof().is_err());
+}
+
+#[test]
+fn variant_added_by_the_caller() {
+    assert_eq!(level(3).expect_high(3), Some(3));
+    assert_eq!(level(3).into_high(), Some(3));
+    assert_eq!(Level::Low(1).into_high(), None);
+}
+
+/// a module generated by the macro, with the items generated next to the enum.
+macro_rules! in_module {
+    ($module:ident, $ext:literal) => {
+        mod $module {
+            use expect_macro_derive::Expect;
+
+            #[derive(Debug, PartialEq, Expect)]
+            #[expect_macro(module = $ext, ref_enum, detailed, pin_projections, variant_count, take)]
+            pub enum Shape {
+                #[return_struct(derive(Debug, PartialEq))]
+                Rect { w: u32, h: u32 },
+                #[return_struct(name = "CircleData", derive(Debug, PartialEq))]
+                Circle(u32),
+                Empty,
+            }
+        }
+    };
+}
+
+in_module!(shapes, "shape_ext");
+
+mod caller {
+    use super::shapes::{CircleData, Shape, ShapeMatchResult, ShapeRect, ShapeRef};
+    use super::shapes::shape_ext::ShapeExt;
+
+    #[test]
+    fn items_generated_in_a_macro_module() {
+        assert_eq!(Shape::Rect { w: 1, h: 2 }.expect_rect(1, 2), Some(ShapeRect { w: 1, h: 2 }));
+        assert_eq!(Shape::Circle(1).expect_circle_detailed(1), ShapeMatchResult::Match(CircleData(1)));
+        assert!(matches!(Shape::Circle(1).as_ref_enum(), ShapeRef::Circle(1)));
+        assert_eq!(Shape::VARIANT_COUNT, 3);
+
+        let mut shape = Shape::Circle(2);
+        assert_eq!(shape.take_circle(), Some(CircleData(2)));
+        assert_eq!(shape, Shape::Empty);
+    }
+}
@@ -0,0 +1,102 @@
+use expect_macro_derive::Expect;
+
+mod events {
+    use expect_macro_derive::Expect;
+
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    #[derive(Debug, PartialEq, Expect)]
+    #[expect_macro(module = "event_ext", as_ref, assertions)]
+    pub enum Event<T: PartialEq> {
+        Click { x: i32, y: i32 },
+        Move(PointWrapper),
+        Custom(T),
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct PointWrapper(pub i32);
+
+    impl Point {
+        pub fn wrap(&self) -> PointWrapper {
+            PointWrapper(self.x + self.y)
+        }
+    }
+}
+
+#[derive(Expect)]
+#[expect_macro(module = "foo_ext")]
+enum Foo {
+    Bar(i32),
+    Qux,
+}
+
+#[test]
+fn methods_are_in_the_generated_trait() {
+    use events::event_ext::EventExt;
+    use events::Event;
+
+    let click = Event::<()>::Click { x: 1, y: 2 };
+    assert_eq!(click.as_click(), Some((&1, &2)));
+    click.assert_click();
+    assert_eq!(click.expect_click(1, 2), Some((1, 2)));
+
+    let point = events::Point { x: 1, y: 2 };
+    assert_eq!(Event::<()>::Move(point.wrap()).expect_move(events::PointWrapper(3)), Some(events::PointWrapper(3)));
+    assert_eq!(Event::Custom("a").expect_custom("a"), Some("a"));
+}
+
+#[test]
+fn private_enum_module() {
+    use foo_ext::FooExt;
+
+    assert_eq!(Foo::Bar(1).expect_bar(1), Some(1));
+    assert_eq!(Foo::Qux.expect_bar(1), None);
+}
+
+mod restricted {
+    pub mod inner {
+        use expect_macro_derive::Expect;
+
+        #[derive(Expect)]
+        #[expect_macro(module = "crate_ext")]
+        pub(crate) enum CrateVisible {
+            Bar(i32),
+        }
+
+        #[derive(Expect)]
+        #[expect_macro(module = "super_ext")]
+        pub(super) enum SuperVisible {
+            Bar(i32),
+        }
+
+        #[derive(Expect)]
+        #[expect_macro(module = "path_ext")]
+        pub(in crate::restricted) enum PathVisible {
+            Bar(i32),
+        }
+    }
+
+    pub fn super_visible() -> Option<i32> {
+        use inner::super_ext::SuperVisibleExt;
+
+        inner::SuperVisible::Bar(2).expect_bar(2)
+    }
+
+    pub fn path_visible() -> Option<i32> {
+        use inner::path_ext::PathVisibleExt;
+
+        inner::PathVisible::Bar(3).expect_bar(3)
+    }
+}
+
+#[test]
+fn restricted_visibility() {
+    use restricted::inner::crate_ext::CrateVisibleExt;
+
+    assert_eq!(restricted::inner::CrateVisible::Bar(1).expect_bar(1), Some(1));
+    assert_eq!(restricted::super_visible(), Some(2));
+    assert_eq!(restricted::path_visible(), Some(3));
+}
@@ -0,0 +1,39 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(mut_accessors)]
+enum Job {
+    Task { state: &'static str, #[ignore_eq] retries: u32 },
+    #[panic]
+    Done(String),
+}
+
+fn retry(job: &mut Job) {
+    if let Some((state, retries)) = job.expect_task_mut(&"failed") {
+        *state = "pending";
+        *retries += 1;
+    }
+}
+
+#[test]
+fn mutates_only_matching_values() {
+    let mut job = Job::Task { state: "failed", retries: 0 };
+    retry(&mut job);
+    assert_eq!(job, Job::Task { state: "pending", retries: 1 });
+
+    retry(&mut job);
+    assert_eq!(job, Job::Task { state: "pending", retries: 1 });
+}
+
+#[test]
+fn panic_variant() {
+    let mut job = Job::Done("ok".to_string());
+    job.expect_done_mut(&"ok".to_string()).push('!');
+    assert_eq!(job, Job::Done("ok!".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "expected Job::Done, found Task")]
+fn panic_variant_on_mismatch() {
+    Job::Task { state: "failed", retries: 0 }.expect_done_mut(&"ok".to_string());
+}
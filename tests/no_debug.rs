@@ -0,0 +1,68 @@
+#![allow(dead_code)]
+
+use std::fmt;
+
+use expect_macro_derive::Expect;
+
+/// a payload which doesn't implement Debug.
+struct Handle(u32);
+
+#[derive(Expect)]
+#[expect_macro(unwrap)]
+enum Job {
+    #[panic]
+    Ready(u32),
+    #[panic]
+    #[no_debug]
+    Running(#[ignore_eq] Handle),
+    Done,
+}
+
+impl fmt::Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Job::Ready(id) => f.debug_tuple("Ready").field(id).finish(),
+            Job::Running(_) => f.write_str("Running(..)"),
+            Job::Done => f.write_str("Done"),
+        }
+    }
+}
+
+/// an enum without Debug, since its only panicking variant is `#[no_debug]`.
+#[derive(Expect)]
+enum Worker {
+    #[panic]
+    #[no_debug]
+    Busy(#[ignore_eq] Handle),
+    Idle,
+}
+
+#[test]
+#[should_panic(expected = "expected Ready(1), found Done")]
+fn debug_variant_shows_the_value() {
+    Job::Done.expect_ready(1);
+}
+
+#[test]
+#[should_panic(expected = "expected Job::Running, found Ready")]
+fn no_debug_variant_shows_the_name() {
+    Job::Ready(1).expect_running();
+}
+
+#[test]
+#[should_panic(expected = "expected Job::Running, found Done")]
+fn no_debug_unwrap() {
+    Job::Done.unwrap_running();
+}
+
+#[test]
+fn no_debug_variant_matching() {
+    assert_eq!(Job::Running(Handle(3)).expect_running().0, 3);
+    assert_eq!(Job::Running(Handle(3)).unwrap_running().0, 3);
+}
+
+#[test]
+#[should_panic(expected = "expected Worker::Busy, found Idle")]
+fn enum_without_debug() {
+    Worker::Idle.expect_busy();
+}
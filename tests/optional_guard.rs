@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(optional_guard, cloned_accessors, mut_accessors, detailed)]
+enum Request {
+    Get { path: String, port: u16 },
+    #[panic]
+    Post(String, Vec<u8>),
+    Close,
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(optional_guard, eq_impl, eq_ref)]
+enum Named {
+    Person { name: String, #[guard(epsilon = 0.5)] height: f64 },
+}
+
+#[test]
+fn none_matches_any_value() {
+    let get = Request::Get { path: "/".to_string(), port: 80 };
+    assert_eq!(get.clone().expect_get(None, Some(80)), Some(("/".to_string(), 80)));
+    assert_eq!(get.clone().expect_get(Some("/".to_string()), None), Some(("/".to_string(), 80)));
+    assert_eq!(get.clone().expect_get(None, None), Some(("/".to_string(), 80)));
+    assert_eq!(get.clone().expect_get(Some("/a".to_string()), None), None);
+    assert_eq!(get.expect_get_cloned(None, Some(81)), None);
+    assert_eq!(Request::Close.expect_get(None, None), None);
+}
+
+#[test]
+fn other_methods() {
+    let mut post = Request::Post("/".to_string(), vec![1]);
+    post.expect_post_mut(None, Some(&vec![1])).0.push('a');
+    assert_eq!(post.clone().expect_post(Some("/a".to_string()), None), ("/a".to_string(), vec![1]));
+    assert_eq!(post.expect_post_detailed(None, Some(vec![2])), RequestMatchResult::ValueMismatch(("/a".to_string(), vec![1])));
+}
+
+#[test]
+fn with_eq_impl_and_eq_ref() {
+    let person = Named::Person { name: "alice".to_string(), height: 1.7 };
+    assert!(person.expect_person(Some(&"alice"), None).is_some());
+    let person = Named::Person { name: "alice".to_string(), height: 1.7 };
+    assert!(person.expect_person::<&str>(None, Some(&1.5)).is_some());
+}
+
+#[test]
+#[should_panic(expected = "expected Request::Post, found Close")]
+fn panic_message() {
+    Request::Close.expect_post(None, None);
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+struct NoDefault;
+
+#[derive(Expect)]
+#[expect_macro(or_default)]
+enum Setting {
+    Size { width: u32, name: String },
+    Flags(Vec<bool>),
+    Other(NoDefault),
+    Unset,
+}
+
+#[test]
+fn returns_the_fields_on_match() {
+    let size = Setting::Size { width: 10, name: "a".to_string() };
+    assert_eq!(size.expect_size_or_default(), (10, "a".to_string()));
+    assert_eq!(Setting::Flags(vec![true]).expect_flags_or_default(), vec![true]);
+}
+
+#[test]
+fn returns_the_default_on_mismatch() {
+    assert_eq!(Setting::Unset.expect_size_or_default(), (0, String::new()));
+    assert_eq!(Setting::Other(NoDefault).expect_flags_or_default(), Vec::new());
+}
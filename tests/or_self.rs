@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(or_self)]
+enum Response {
+    #[panic]
+    Ok { status: u16, #[ignore_eq] body: String },
+    Redirect(String),
+    Timeout,
+}
+
+#[test]
+fn matching_variant_and_values() {
+    let response = Response::Ok { status: 200, body: "hi".to_string() };
+    assert_eq!(response.expect_ok_or_self(200), Ok((200, "hi".to_string())));
+}
+
+#[test]
+fn inspect_what_arrived() {
+    let response = Response::Ok { status: 500, body: "oops".to_string() };
+    match response.expect_ok_or_self(200) {
+        Err(Response::Ok { status, body }) => assert_eq!((status, body.as_str()), (500, "oops")),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    assert_eq!(Response::Timeout.expect_ok_or_self(200), Err(Response::Timeout));
+    assert_eq!(Response::Redirect("/a".to_string()).expect_redirect_or_self("/b".to_string()), Err(Response::Redirect("/a".to_string())));
+}
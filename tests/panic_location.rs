@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(mut_accessors, predicate_accessors)]
+enum State {
+    #[panic(with_location)]
+    Ready(u32),
+    #[panic(with_location)]
+    #[expect_macro(no_construct)]
+    Named { id: u32 },
+    #[panic]
+    Plain(u32),
+    Idle,
+}
+
+#[test]
+#[should_panic(expected = "expected Ready(1), found Idle at tests/panic_location.rs:")]
+fn location_in_the_message() {
+    State::Idle.expect_ready(1);
+}
+
+#[test]
+#[should_panic(expected = "expected State::Named, found Idle at tests/panic_location.rs:27:")]
+fn location_of_the_caller() {
+    State::Idle.expect_named(1);
+}
+
+#[test]
+#[should_panic(expected = "expected State::Ready, found Ready(2) at tests/panic_location.rs:")]
+fn location_in_the_other_methods() {
+    State::Ready(2).expect_ready_if(|n| *n == 1);
+}
+
+#[test]
+#[should_panic(expected = "expected State::Ready, found Idle at tests/panic_location.rs:")]
+fn location_in_mut_accessor() {
+    State::Idle.expect_ready_mut(&1);
+}
+
+#[test]
+fn without_location() {
+    let message = std::panic::catch_unwind(|| State::Idle.expect_plain(1)).unwrap_err();
+    assert_eq!(message.downcast_ref::<String>().unwrap(), "expected Plain(1), found Idle");
+}
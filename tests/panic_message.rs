@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+enum Foo {
+    #[panic]
+    Bar { a: i32, b: i32 },
+    Baz(i32, i32),
+}
+
+#[test]
+#[should_panic(expected = "found")]
+fn message_describes_the_actual_value() {
+    Foo::Baz(1, 2).expect_bar(1, 2);
+}
+
+#[test]
+#[should_panic(expected = "expected Bar { a: 1, b: 2 }, found Baz(1, 2)")]
+fn message_labels_expected_and_found() {
+    Foo::Baz(1, 2).expect_bar(1, 2);
+}
+
+#[test]
+#[should_panic(expected = "expected Bar { a: 1, b: 3 }, found Bar { a: 1, b: 2 }")]
+fn message_on_guard_mismatch() {
+    Foo::Bar { a: 1, b: 2 }.expect_bar(1, 3);
+}
+
+mod private {
+    use expect_macro_derive::Expect;
+
+    type Secret = u32;
+
+    #[derive(Debug, Expect)]
+    pub enum Token {
+        #[panic]
+        #[expect_macro(no_construct)]
+        Key(Secret),
+        #[panic]
+        #[non_exhaustive]
+        Other { value: Secret },
+        Empty,
+    }
+}
+
+#[test]
+#[should_panic(expected = "expected Token::Key, found Empty")]
+fn message_without_construction() {
+    private::Token::Empty.expect_key(1);
+}
+
+#[test]
+#[should_panic(expected = "expected Token::Other, found Key(1)")]
+fn message_for_non_exhaustive_variant() {
+    private::Token::Key(1).expect_other(1);
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(panic_message_prefix = "[MyApp]", unwrap, assertions, mut_accessors, predicate_accessors)]
+enum App {
+    #[panic]
+    Ready { id: u32 },
+    #[panic(with_location)]
+    Failed(String),
+    Starting,
+}
+
+#[test]
+#[should_panic(expected = "[MyApp] expected Ready { id: 1 }, found Starting")]
+fn prefixed_panic_variant() {
+    App::Starting.expect_ready(1);
+}
+
+#[test]
+#[should_panic(expected = "[MyApp] expected App::Failed, found Starting at ")]
+fn prefixed_with_location() {
+    App::Starting.expect_failed_if(|_| true);
+}
+
+#[test]
+#[should_panic(expected = "[MyApp] expected App::Ready, found Starting")]
+fn prefixed_mut_accessor() {
+    App::Starting.expect_ready_mut(&1);
+}
+
+#[test]
+#[should_panic(expected = "[MyApp] expected App::Starting, found Ready { id: 1 }")]
+fn prefixed_unwrap() {
+    App::Ready { id: 1 }.unwrap_starting();
+}
+
+#[test]
+#[should_panic(expected = "[MyApp] assertion failed: expected `App::Failed`, found `App::Starting`")]
+fn prefixed_assertion() {
+    App::Starting.assert_failed();
+}
@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(payload_type_name)]
+enum Value<'a, T> {
+    Int(i64),
+    Text { value: String, len: usize },
+    Borrowed(&'a str),
+    Generic(T),
+    Nested(Vec<u8>),
+    Empty,
+}
+
+type V = Value<'static, ()>;
+
+#[test]
+fn first_field_type() {
+    assert_eq!(V::Int(1).payload_type_name(), Some("i64"));
+    assert_eq!(V::Text { value: String::new(), len: 0 }.payload_type_name(), Some("String"));
+    assert_eq!(Value::Generic(1u8).payload_type_name(), Some("T"));
+    assert_eq!(V::Borrowed("a").payload_type_name().map(|name| name.replace(' ', "")), Some("&'astr".to_string()));
+    assert_eq!(V::Nested(vec![]).payload_type_name().map(|name| name.replace(' ', "")), Some("Vec<u8>".to_string()));
+}
+
+#[test]
+fn unit_variant() {
+    assert_eq!(V::Empty.payload_type_name(), None);
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Default, PartialEq)]
+struct Meters;
+
+#[derive(Debug, Expect)]
+#[expect_macro(as_ref, ref_enum, unwrap)]
+enum Length<T: std::fmt::Debug> {
+    Tagged(u32, PhantomData<T>),
+    #[panic]
+    Named { value: u32, unit: PhantomData<T> },
+    Marked {
+        value: u32,
+        #[phantom]
+        unit: Meters,
+    },
+    Empty,
+}
+
+#[test]
+fn only_the_real_field_is_returned() {
+    let tagged = Length::<Meters>::Tagged(1, PhantomData);
+    assert_eq!(tagged.as_tagged(), Some(&1));
+    assert_eq!(tagged.expect_tagged(1), Some(1));
+    assert_eq!(Length::<Meters>::Tagged(1, PhantomData).expect_tagged(2), None);
+    assert_eq!(Length::<Meters>::Named { value: 3, unit: PhantomData }.expect_named(3), 3);
+}
+
+#[test]
+fn phantom_attribute() {
+    let marked = Length::<()>::Marked { value: 4, unit: Meters };
+    assert_eq!(marked.as_marked(), Some(&4));
+    assert_eq!(marked.unwrap_marked(), 4);
+}
+
+#[test]
+#[should_panic(expected = "expected Named { value: 3, unit: PhantomData<phantom::Meters> }, found Named { value: 2, unit: PhantomData<phantom::Meters> }")]
+fn panic_message_builds_the_phantom_field() {
+    Length::<Meters>::Named { value: 2, unit: PhantomData }.expect_named(3);
+}
+
+#[test]
+fn ref_enum_keeps_the_phantom_fields() {
+    let tagged = Length::<Meters>::Tagged(5, PhantomData);
+    match tagged.as_ref_enum() {
+        LengthRef::Tagged(value, _) => assert_eq!(*value, 5),
+        _ => unreachable!(),
+    }
+}
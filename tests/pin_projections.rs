@@ -0,0 +1,90 @@
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(pin_projections)]
+enum State {
+    Running(Countdown, u32),
+    Done { result: u32 },
+}
+
+/// a `!Unpin` future, ready after `remaining` polls.
+struct Countdown {
+    remaining: u32,
+    _pinned: PhantomPinned,
+}
+
+impl Future for Countdown {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.remaining == 0 {
+            Poll::Ready(())
+        } else {
+            this.remaining -= 1;
+            Poll::Pending
+        }
+    }
+}
+
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    unsafe { Waker::from_raw(clone(std::ptr::null())) }
+}
+
+#[test]
+fn projects_the_fields() {
+    let mut state = Box::pin(State::Running(Countdown { remaining: 2, _pinned: PhantomPinned }, 7));
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut polls = 0;
+    loop {
+        let (countdown, count) = state.as_mut().as_running_pin().unwrap();
+        *count.get_mut() += 1;
+        polls += 1;
+        if countdown.poll(&mut cx).is_ready() {
+            break;
+        }
+    }
+    assert_eq!(polls, 3);
+    assert!(state.as_mut().as_done_pin().is_none());
+
+    let result = match state.as_mut().as_running_pin() {
+        Some((_, count)) => *count,
+        None => unreachable!(),
+    };
+    state.set(State::Done { result });
+    assert_eq!(*state.as_mut().as_done_pin().unwrap(), 10);
+}
+
+#[derive(Expect)]
+#[expect_macro(pin_projections)]
+enum Slot<T> {
+    Full(T, u32),
+    Empty,
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+#[test]
+fn unpin_when_all_the_fields_are() {
+    assert_unpin::<Slot<String>>();
+
+    let mut slot = Slot::Full("a".to_string(), 1);
+    let (value, _) = Pin::new(&mut slot).as_full_pin().unwrap();
+    value.get_mut().push('b');
+    let moved = slot;
+    assert_eq!(Pin::new(&mut { moved }).as_full_pin().map(|(value, _)| value.get_mut().clone()), Some("ab".to_string()));
+}
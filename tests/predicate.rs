@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(predicate_accessors)]
+enum Reading {
+    Pair(i32, u8),
+    Named { label: String },
+    #[panic]
+    Strict(u32),
+    Empty,
+}
+
+#[test]
+fn predicate_accepts() {
+    assert_eq!(Reading::Pair(1, 2).expect_pair_if(|a, b| *a > 0 && *b == 2), Some((1, 2)));
+    assert_eq!(
+        Reading::Named { label: "ok".to_string() }.expect_named_if(|label| label.starts_with('o')),
+        Some("ok".to_string())
+    );
+    assert_eq!(Reading::Strict(3).expect_strict_if(|n| n % 2 == 1), 3);
+}
+
+#[test]
+fn predicate_rejects() {
+    assert_eq!(Reading::Pair(-1, 2).expect_pair_if(|a, b| *a > 0 && *b == 2), None);
+    assert_eq!(Reading::Pair(1, 3).expect_pair_if(|a, b| *a > 0 && *b == 2), None);
+    assert_eq!(Reading::Empty.expect_pair_if(|_, _| true), None);
+}
+
+#[test]
+#[should_panic(expected = "expected Reading::Strict, found Strict(2)")]
+fn panic_variant_rejects() {
+    Reading::Strict(2).expect_strict_if(|n| n % 2 == 1);
+}
@@ -0,0 +1,53 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(into, as_ref, into_prefix = "take", ref_prefix = "peek")]
+enum Foo {
+    Bar { a: i32, b: String },
+    Qux,
+}
+
+// these would collide with the default families if they were still generated.
+impl Foo {
+    fn into_bar(self) -> &'static str {
+        "user into_bar"
+    }
+
+    fn as_bar(&self) -> &'static str {
+        "user as_bar"
+    }
+}
+
+#[test]
+fn renamed_families() {
+    let bar = Foo::Bar { a: 1, b: "b".to_string() };
+    assert_eq!(bar.peek_bar(), Some((&1, &"b".to_string())));
+    assert_eq!(bar.peek_qux(), None);
+    assert_eq!(bar.take_bar(), Some((1, "b".to_string())));
+    assert_eq!(Foo::Qux.take_qux(), Some(()));
+}
+
+#[test]
+fn default_families_are_gone() {
+    assert_eq!(Foo::Qux.into_bar(), "user into_bar");
+    assert_eq!(Foo::Qux.as_bar(), "user as_bar");
+}
+
+#[derive(Expect)]
+#[expect_macro(prefix = "into")]
+enum Inherent {
+    Bar(i32),
+}
+
+impl Inherent {
+    fn expect_bar(&self) -> bool {
+        matches!(self, Inherent::Bar(_))
+    }
+}
+
+#[test]
+fn prefix_avoids_inherent_collision() {
+    assert!(Inherent::Bar(1).expect_bar());
+    assert_eq!(Inherent::Bar(1).into_bar(1), Some(1));
+    assert_eq!(Inherent::Bar(1).into_bar(2), None);
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(prefix_by_arity, into)]
+enum State {
+    Loaded(String),
+    Loading { progress: u8 },
+    Idle,
+    #[rename = "stopped"]
+    Halted,
+    #[expect_macro(rename_method = "check_failed")]
+    Failed,
+    #[panic]
+    Ready,
+}
+
+#[derive(Expect)]
+#[expect_macro(prefix_by_arity, is)]
+enum Light {
+    Color(u8),
+    Off,
+}
+
+#[test]
+fn arity_based_names() {
+    assert_eq!(State::Loaded("a".to_string()).expect_loaded("a".to_string()), Some("a".to_string()));
+    assert_eq!(State::Loading { progress: 5 }.into_loading(), Some(5));
+    assert!(State::Idle.is_idle());
+    assert!(!State::Loaded("a".to_string()).is_idle());
+    assert!(State::Halted.is_stopped());
+}
+
+#[test]
+fn explicit_names_and_panics_keep_expect() {
+    assert_eq!(State::Failed.check_failed(), Some(()));
+    State::Ready.expect_ready();
+}
+
+#[test]
+fn with_the_is_family() {
+    assert!(Light::Off.is_off());
+    assert!(Light::Color(1).is_color());
+    assert_eq!(Light::Color(1).expect_color(1), Some(1));
+}
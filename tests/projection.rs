@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(projection, ref_enum)]
+enum Shape<T> {
+    Circle { radius: T },
+    Rect(T, T),
+    Empty,
+}
+
+fn area(shape: &Shape<f64>) -> f64 {
+    match shape.project() {
+        ShapeProjection::Circle { radius } => 3.0 * radius * radius,
+        ShapeProjection::Rect(w, h) => w * h,
+        ShapeProjection::Empty => 0.0,
+    }
+}
+
+#[test]
+fn match_on_the_projection() {
+    assert_eq!(area(&Shape::Circle { radius: 1.0 }), 3.0);
+    assert_eq!(area(&Shape::Rect(2.0, 3.0)), 6.0);
+    assert_eq!(area(&Shape::Empty), 0.0);
+}
+
+#[test]
+fn alongside_ref_enum() {
+    let rect = Shape::Rect(1, 2);
+    assert!(matches!(rect.as_ref_enum(), ShapeRef::Rect(1, 2)));
+    assert_eq!(rect.project().to_owned(), rect);
+}
@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(as_ref, getters)]
+enum Token {
+    Keyword { r#type: String, r#match: bool },
+    #[panic]
+    Strict { r#in: u8 },
+}
+
+#[test]
+fn raw_field_names() {
+    let keyword = || Token::Keyword { r#type: "fn".to_string(), r#match: true };
+    assert_eq!(keyword().expect_keyword("fn".to_string(), true), Some(("fn".to_string(), true)));
+    assert_eq!(keyword().as_keyword(), Some((&"fn".to_string(), &true)));
+    assert_eq!(keyword().get_keyword_type(), Some(&"fn".to_string()));
+    assert_eq!(Token::Strict { r#in: 1 }.expect_strict(1), 1);
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(tuple_args, cloned_accessors)]
+enum Ordered {
+    #[stable_order]
+    Pair { r#type: u8, a: u8 },
+}
+
+#[test]
+fn raw_field_names_in_stable_order() {
+    assert_eq!(Ordered::Pair { r#type: 2, a: 1 }.expect_pair((1, 2)), Some((1, 2)));
+    assert_eq!(Ordered::Pair { r#type: 2, a: 1 }.expect_pair_cloned((1, 2)), Some((1, 2)));
+}
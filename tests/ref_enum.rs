@@ -0,0 +1,80 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(ref_enum)]
+enum Foo {
+    Bar { a: String, b: Vec<u8> },
+    Baz(String),
+    Qux,
+}
+
+#[derive(Expect)]
+#[expect_macro(ref_enum)]
+enum Generic<'a, T: PartialEq> {
+    Value(&'a T),
+    Owned(T),
+}
+
+#[test]
+fn match_on_ref_enum() {
+    let foo = Foo::Bar { a: "a".to_string(), b: vec![1, 2] };
+    match foo.as_ref_enum() {
+        FooRef::Bar { a, b } => {
+            assert_eq!(a, "a");
+            assert_eq!(b, &[1, 2]);
+        },
+        FooRef::Baz(_) | FooRef::Qux => panic!("expected Bar"),
+    }
+
+    let baz = Foo::Baz("baz".to_string());
+    assert!(matches!(baz.as_ref_enum(), FooRef::Baz(s) if s == "baz"));
+    assert!(matches!(Foo::Qux.as_ref_enum(), FooRef::Qux));
+}
+
+#[test]
+fn ref_enum_keeps_generics() {
+    let value = 1;
+    let generic = Generic::Value(&value);
+    assert!(matches!(generic.as_ref_enum(), GenericRef::Value(&&1)));
+    assert!(matches!(Generic::<i32>::Owned(2).as_ref_enum(), GenericRef::Owned(&2)));
+}
+
+struct NotClone;
+
+#[derive(Expect)]
+#[expect_macro(ref_enum, into)]
+enum Mixed {
+    Clonable(String, u32),
+    Opaque(Vec<u8>),
+}
+
+#[derive(PartialEq)]
+struct NoCloneAllowed(NotClone);
+
+impl PartialEq for NotClone {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+// `to_owned` can't be used as the field isn't `Clone`, but it doesn't prevent the derive.
+#[derive(Expect)]
+#[expect_macro(ref_enum)]
+enum WithoutClone {
+    Value(NoCloneAllowed),
+}
+
+#[test]
+fn ref_enum_to_owned() {
+    let mixed = Mixed::Clonable("a".to_string(), 1);
+    let borrowed = mixed.as_ref_enum();
+    match borrowed {
+        MixedRef::Clonable(name, _) => assert_eq!(name, "a"),
+        MixedRef::Opaque(_) => panic!("expected Clonable"),
+    }
+    assert_eq!(borrowed.to_owned().into_clonable(), Some(("a".to_string(), 1)));
+    assert_eq!(mixed.into_clonable(), Some(("a".to_string(), 1)));
+
+    let without_clone = WithoutClone::Value(NoCloneAllowed(NotClone));
+    assert!(matches!(without_clone.as_ref_enum(), WithoutCloneRef::Value(_)));
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(into, prefix = "get")]
+enum Message {
+    #[expect_macro(rename_method = "payload")]
+    Data(Vec<u8>),
+    #[panic]
+    #[expect_macro(rename_method = "ack_id")]
+    Ack { id: u32 },
+    Close,
+}
+
+#[test]
+fn overridden_method_name() {
+    assert_eq!(Message::Data(vec![1]).payload(vec![1]), Some(vec![1]));
+    assert_eq!(Message::Close.payload(vec![1]), None);
+    assert_eq!(Message::Ack { id: 1 }.ack_id(1), 1);
+}
+
+#[test]
+fn other_methods_keep_their_names() {
+    assert_eq!(Message::Data(vec![1]).into_data(), Some(vec![1]));
+    assert_eq!(Message::Close.get_close(), Some(()));
+}
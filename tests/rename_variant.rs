@@ -0,0 +1,44 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(into, is)]
+enum Unit {
+    #[rename = "size"]
+    Größe(u32),
+    #[rename = "http_request"]
+    HttpRequest,
+    #[expect_macro(construct)]
+    #[rename = "meters"]
+    Metre(f64),
+}
+
+#[test]
+fn renamed_variants() {
+    assert_eq!(Unit::Größe(1).into_size(), Some(1));
+    assert!(Unit::HttpRequest.is_http_request());
+    assert_eq!(Unit::meters(1.5).expect_meters(1.5), Some(1.5));
+}
+
+#[derive(Debug, Expect)]
+#[expect_macro(into, is, getters, predicate_accessors)]
+enum Call {
+    // both would be `httprequest` without the rename.
+    HttpRequest(u16),
+    #[rename = "http_request_legacy"]
+    Httprequest(u16),
+    // `get_bar_a_b` and `expect_bar_if` are only generated once each.
+    Bar { a_b: u8 },
+    BarA { b: u8 },
+    BarIf,
+}
+
+#[test]
+fn near_collision_resolved_by_rename() {
+    assert_eq!(Call::HttpRequest(1).into_httprequest(), Some(1));
+    assert_eq!(Call::Httprequest(2).into_http_request_legacy(), Some(2));
+    assert!(!Call::Httprequest(2).is_httprequest());
+    assert_eq!(Call::Bar { a_b: 1 }.get_bar_a_b(), Some(&1));
+    assert_eq!(Call::BarA { b: 2 }.get_bara_b(), Some(&2));
+    assert_eq!(Call::Bar { a_b: 1 }.expect_bar_if(|a_b| *a_b == 1), Some(1));
+    assert!(Call::BarIf.is_barif());
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(as_ref, as_mut, is, into, as_discriminant, variant_rank)]
+#[repr(C)]
+enum Ffi {
+    Point { x: i32, y: i32 },
+    Value(u64),
+    Null,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Expect)]
+#[expect_macro(repr_code, as_ref, is)]
+#[repr(C, u8)]
+enum Tagged {
+    Byte(u8) = 2,
+    Word(u16),
+    Empty = 10,
+}
+
+#[test]
+fn safe_accessors_on_repr_c() {
+    let mut point = Ffi::Point { x: 1, y: 2 };
+    assert_eq!(point.as_point(), Some((&1, &2)));
+    *point.as_mut_point().unwrap().0 = 3;
+    assert_eq!(point.clone().expect_point(3, 2), Some((3, 2)));
+    assert_eq!(point.clone().into_point(), Some((3, 2)));
+    assert!(Ffi::Null.is_null());
+    assert_eq!(Ffi::Value(1).as_discriminant(), Ffi::Value(2).as_discriminant());
+    assert_eq!(Ffi::Null.variant_rank(), 2);
+}
+
+#[test]
+fn code_follows_the_integer_of_repr_c_u8() {
+    assert_eq!(Tagged::Byte(1).code(), 2);
+    assert_eq!(Tagged::Word(1).code(), 3);
+    assert_eq!(Tagged::Empty.code(), 10);
+    assert_eq!(Tagged::Word(4).as_word(), Some(&4));
+    assert_eq!(Tagged::Byte(5).expect_byte(5), Some(5));
+}
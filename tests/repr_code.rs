@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(repr_code)]
+#[repr(u8)]
+enum Packet {
+    Ping = 1,
+    Data(Vec<u8>) = 4,
+    Ack { id: u32 },
+    Close = 1 << 4,
+    Error(String),
+}
+
+#[derive(Expect)]
+#[expect_macro(repr_code)]
+#[repr(C, i16)]
+enum Implicit {
+    First(i32),
+    Second,
+    Third { a: bool },
+}
+
+#[test]
+fn explicit_codes() {
+    assert_eq!(Packet::Ping.code(), 1);
+    assert_eq!(Packet::Data(vec![1]).code(), 4);
+    assert_eq!(Packet::Close.code(), 16);
+}
+
+#[test]
+fn implicit_codes_follow_the_previous_one() {
+    assert_eq!(Packet::Ack { id: 1 }.code(), 5);
+    assert_eq!(Packet::Error(String::new()).code(), 17);
+    assert_eq!(Implicit::First(1).code(), 0);
+    assert_eq!(Implicit::Second.code(), 1);
+    assert_eq!(Implicit::Third { a: true }.code(), 2);
+}
+
+#[test]
+fn codes_match_the_layout() {
+    let ack = Packet::Ack { id: 1 };
+    assert_eq!(unsafe { *(&ack as *const Packet as *const u8) }, ack.code());
+}
+
+#[derive(Clone, Copy, Expect)]
+#[expect_macro(repr_code)]
+#[repr(i8)]
+enum Mixed {
+    A = 1,
+    B,
+    C = 10,
+    D,
+    E = -3,
+    F,
+    G,
+}
+
+#[test]
+fn mixed_explicit_and_implicit_codes() {
+    let all = [Mixed::A, Mixed::B, Mixed::C, Mixed::D, Mixed::E, Mixed::F, Mixed::G];
+    assert_eq!(all.iter().map(Mixed::code).collect::<Vec<_>>(), [1, 2, 10, 11, -3, -2, -1]);
+    for value in all {
+        assert_eq!(value.code(), value as i8);
+    }
+}
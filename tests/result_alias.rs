@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug)]
+struct MyError;
+
+// a crate-level alias shadowing the prelude `Result`, the generated methods don't use it.
+type Result<T> = std::result::Result<T, MyError>;
+
+fn parse(value: u8) -> Result<u8> {
+    if value > 0 { Ok(value) } else { Err(MyError) }
+}
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(try_get, or_self, try_as)]
+enum Value {
+    Number(u8),
+    Empty,
+}
+
+#[test]
+fn try_get_with_a_result_alias() {
+    assert_eq!(Value::Number(1).try_get_number(), Ok(1));
+    assert!(Value::Empty.try_get_number().is_err());
+    assert!(parse(0).is_err());
+}
+
+#[test]
+fn or_self_with_a_result_alias() {
+    assert_eq!(Value::Number(1).expect_number_or_self(1), Ok(1));
+    assert_eq!(Value::Empty.expect_number_or_self(1), Err(Value::Empty));
+}
+
+#[test]
+fn try_as_with_a_result_alias() {
+    assert_eq!(Value::Number(1).try_as_number(), Ok(&1));
+    assert_eq!(Value::Empty.try_as_number().unwrap_err().found, "Empty");
+}
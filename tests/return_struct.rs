@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, Expect)]
+#[expect_macro(into, unwrap, try_get, cloned_accessors)]
+enum Event {
+    #[return_struct(derive(Debug, PartialEq))]
+    Click { x: i32, y: i32 },
+    #[return_struct(derive(Debug, Clone, PartialEq))]
+    Scroll(f64),
+    #[return_struct]
+    Key(char),
+    Resize(u32, u32),
+    Quit,
+}
+
+#[test]
+fn named_variant_returns_struct() {
+    let click = Event::Click { x: 1, y: 2 };
+    assert_eq!(click.expect_click_cloned(1, 2), Some(EventClick { x: 1, y: 2 }));
+    assert_eq!(click.clone().into_click().unwrap(), EventClick { x: 1, y: 2 });
+    assert_eq!(click.unwrap_click(), EventClick { x: 1, y: 2 });
+}
+
+#[test]
+fn tuple_variant_returns_tuple_struct() {
+    assert_eq!(Event::Scroll(0.5).try_get_scroll(), Ok(EventScroll(0.5)));
+    assert_eq!(Event::Quit.try_get_scroll(), Err("Quit"));
+    let EventKey(key) = Event::Key('a').expect_key('a').unwrap();
+    assert_eq!(key, 'a');
+}
+
+#[test]
+fn other_variants_return_tuples() {
+    assert_eq!(Event::Resize(1, 2).into_resize(), Some((1, 2)));
+}
+
+#[derive(Debug, PartialEq, Expect)]
+enum Tree<T: std::fmt::Debug + PartialEq> {
+    #[return_struct(derive(Debug, PartialEq))]
+    #[panic]
+    Node { value: T, children: Vec<Self> },
+}
+
+#[test]
+fn generic_struct_with_self() {
+    let tree = Tree::Node { value: 1, children: Vec::new() };
+    assert_eq!(tree.expect_node(1, Vec::new()), TreeNode { value: 1, children: Vec::new() });
+}
+
+#[derive(Expect)]
+enum Input {
+    #[return_struct(name = "ClickData", derive(Debug, PartialEq))]
+    Click { x: i32, y: i32 },
+    #[return_struct(derive(Debug, PartialEq), name = "KeyData")]
+    Key(char),
+}
+
+#[test]
+fn custom_struct_name() {
+    assert_eq!(Input::Click { x: 1, y: 2 }.expect_click(1, 2), Some(ClickData { x: 1, y: 2 }));
+    assert_eq!(Input::Key('a').expect_key('a'), Some(KeyData('a')));
+}
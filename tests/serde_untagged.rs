@@ -0,0 +1,26 @@
+use expect_macro_derive::Expect;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Expect)]
+#[serde(untagged)]
+#[expect_macro(serde_untagged_helper)]
+enum Value {
+    Number(i64),
+    Text(String),
+    Point { x: f64, y: f64 },
+}
+
+#[test]
+fn inspect_deserialized_variant() {
+    let number: Value = serde_json::from_str("1").unwrap();
+    assert!(number.is_number());
+    assert_eq!(number.as_number(), Some(&1));
+
+    let text: Value = serde_json::from_str("\"a\"").unwrap();
+    assert!(!text.is_number());
+    assert_eq!(text.as_text(), Some(&"a".to_string()));
+
+    let point: Value = serde_json::from_str(r#"{ "x": 1.0, "y": 2.0 }"#).unwrap();
+    assert!(point.is_point());
+    assert_eq!(point.as_point(), Some((&1.0, &2.0)));
+}
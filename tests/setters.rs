@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(setters, getters)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect { #[rename = "w"] width_in_pixels: u32, label: String },
+    Point(i32, i32),
+}
+
+#[test]
+fn set_a_field_and_get_the_old_value() {
+    let mut rect = Shape::Rect { width_in_pixels: 10, label: "a".to_string() };
+    assert_eq!(rect.set_rect_label("b".to_string()), Some("a".to_string()));
+    assert_eq!(rect.set_rect_w(20), Some(10));
+    assert_eq!(rect, Shape::Rect { width_in_pixels: 20, label: "b".to_string() });
+    assert_eq!(rect.get_rect_w(), Some(&20));
+}
+
+#[test]
+fn other_variant_is_left_as_is() {
+    let mut circle = Shape::Circle { radius: 1.5 };
+    assert_eq!(circle.set_rect_w(20), None);
+    assert_eq!(circle, Shape::Circle { radius: 1.5 });
+
+    let mut point = Shape::Point(1, 2);
+    assert_eq!(point.set_circle_radius(2.0), None);
+}
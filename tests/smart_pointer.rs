@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(as_ref, as_mut, is, into, cloned_accessors)]
+enum Node {
+    Leaf(i32),
+    Branch { left: i32, right: i32 },
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(clone_on_extract, into)]
+enum Shared {
+    Name(String),
+    Nothing,
+}
+
+#[test]
+fn by_ref_methods_auto_deref() {
+    let mut boxed = Box::new(Node::Leaf(1));
+    assert_eq!(boxed.as_leaf(), Some(&1));
+    assert!(boxed.is_leaf());
+    *boxed.as_mut_leaf().unwrap() = 2;
+    assert_eq!(boxed.expect_leaf_cloned(2), Some(2));
+
+    let rc = Rc::new(Node::Branch { left: 1, right: 2 });
+    assert_eq!(rc.as_branch(), Some((&1, &2)));
+    let arc = Arc::new(Node::Empty);
+    assert!(arc.is_empty());
+}
+
+#[test]
+fn by_value_methods_after_moving_out() {
+    let boxed = Box::new(Node::Leaf(1));
+    assert_eq!((*boxed).into_leaf(), Some(1));
+
+    let rc = Rc::new(Node::Branch { left: 1, right: 2 });
+    assert_eq!(Rc::try_unwrap(rc).unwrap().expect_branch(1, 2), Some((1, 2)));
+}
+
+#[test]
+fn clone_on_extract_through_the_pointer() {
+    let rc = Rc::new(Shared::Name("a".to_string()));
+    assert_eq!(rc.into_name(), Some("a".to_string()));
+    assert_eq!(rc.expect_name("a".to_string()), Some("a".to_string()));
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
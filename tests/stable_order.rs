@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(as_ref, into)]
+enum Event {
+    #[stable_order]
+    Move { y: i32, x: i32, label: &'static str },
+    Click { y: i32, x: i32 },
+}
+
+#[test]
+fn fields_are_sorted_by_name() {
+    let event = Event::Move { y: 2, x: 1, label: "m" };
+    assert_eq!(event.as_move(), Some((&"m", &1, &2)));
+    assert_eq!(event.expect_move("m", 1, 2), Some(("m", 1, 2)));
+}
+
+#[test]
+fn other_variants_keep_declaration_order() {
+    let event = Event::Click { y: 2, x: 1 };
+    assert_eq!(event.into_click(), Some((2, 1)));
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(take)]
+enum Slot {
+    Full { item: String, count: u32 },
+    Reserved(u32),
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(take, sentinel = "Idle")]
+enum Job {
+    Done,
+    Running(Vec<u8>),
+    Idle { since: u64 },
+}
+
+#[test]
+fn take_leaves_the_first_unit_variant() {
+    let mut slot = Slot::Full { item: "a".to_string(), count: 2 };
+    assert_eq!(slot.take_full(), Some(("a".to_string(), 2)));
+    assert_eq!(slot, Slot::Empty);
+    assert_eq!(slot.take_full(), None);
+}
+
+#[test]
+fn take_another_variant_does_nothing() {
+    let mut slot = Slot::Reserved(1);
+    assert_eq!(slot.take_full(), None);
+    assert_eq!(slot, Slot::Reserved(1));
+}
+
+#[test]
+fn configured_sentinel_with_default_fields() {
+    let mut job = Job::Running(vec![1, 2]);
+    assert_eq!(job.take_running(), Some(vec![1, 2]));
+    assert_eq!(job, Job::Idle { since: 0 });
+}
@@ -0,0 +1,27 @@
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+enum Foo {
+    #[panic]
+    Bar(i32),
+    Baz,
+}
+
+#[test]
+fn panic_reports_caller_location() {
+    let location = Arc::new(Mutex::new(None));
+    let hook_location = Arc::clone(&location);
+    panic::set_hook(Box::new(move |info| {
+        *hook_location.lock().unwrap() = info.location().map(|location| (location.file().to_string(), location.line()));
+    }));
+
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| Foo::Baz.expect_bar(1));
+    let _ = panic::take_hook();
+
+    assert!(result.is_err());
+    assert_eq!(*location.lock().unwrap(), Some((file!().to_string(), line)));
+}
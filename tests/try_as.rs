@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(try_as)]
+enum Packet {
+    Data { id: u32, payload: Vec<u8> },
+    Ack(u32),
+    Close,
+}
+
+#[test]
+fn borrowed_fields() {
+    let data = Packet::Data { id: 1, payload: vec![2] };
+    assert_eq!(data.try_as_data(), Ok((&1, &vec![2])));
+    assert_eq!(Packet::Ack(3).try_as_ack(), Ok(&3));
+    assert_eq!(Packet::Close.try_as_close(), Ok(()));
+    // still usable after the inspection.
+    assert!(matches!(data, Packet::Data { id: 1, .. }));
+}
+
+#[test]
+fn expected_and_found_on_mismatch() {
+    let error = Packet::Close.try_as_ack().unwrap_err();
+    assert_eq!(error.expected, "Ack");
+    assert_eq!(error.found, "Close");
+    assert_eq!(error, PacketVariantMismatch { expected: "Ack", found: "Close" });
+    assert_eq!(error.to_string(), "expected Packet::Ack, found Packet::Close");
+
+    let error: Box<dyn std::error::Error> = Box::new(Packet::Ack(1).try_as_data().unwrap_err());
+    assert_eq!(error.to_string(), "expected Packet::Data, found Packet::Ack");
+}
@@ -0,0 +1,17 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(try_get)]
+enum Foo {
+    Bar { a: i32, b: String },
+    Baz(i32),
+    Qux,
+}
+
+#[test]
+fn try_get_returns_the_actual_variant_name() {
+    assert_eq!(Foo::Bar { a: 1, b: "b".to_string() }.try_get_bar(), Ok((1, "b".to_string())));
+    assert_eq!(Foo::Baz(1).try_get_bar(), Err("Baz"));
+    assert_eq!(Foo::Qux.try_get_baz(), Err("Qux"));
+    assert_eq!(Foo::Qux.try_get_qux(), Ok(()));
+}
@@ -0,0 +1,29 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(tuple_args, cloned_accessors)]
+enum Foo {
+    Bar { a: i32, b: String },
+    #[panic]
+    Baz(i32, i32),
+    Single(u8),
+    Qux,
+}
+
+#[test]
+fn guarded_accessor_takes_a_tuple() {
+    let expected = (1, "b".to_string());
+    let bar = Foo::Bar { a: 1, b: "b".to_string() };
+    assert_eq!(bar.expect_bar_cloned(expected.clone()), Some(expected.clone()));
+    assert_eq!(bar.expect_bar(expected.clone()), Some(expected));
+
+    assert_eq!(Foo::Baz(1, 2).expect_baz((1, 2)), (1, 2));
+    assert_eq!(Foo::Single(3).expect_single(3), Some(3));
+    assert_eq!(Foo::Qux.expect_qux(), Some(()));
+}
+
+#[test]
+#[should_panic(expected = "expected Baz(2, 1), found Baz(1, 2)")]
+fn tuple_args_panic_message() {
+    Foo::Baz(1, 2).expect_baz((2, 1));
+}
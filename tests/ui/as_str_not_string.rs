@@ -0,0 +1,8 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    Bar(#[as_str] Vec<u8>),
+}
+
+fn main() {}
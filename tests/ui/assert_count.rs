@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(assert_count = 3)]
+enum Foo {
+    Bar(u8),
+    Baz,
+}
+
+fn main() {}
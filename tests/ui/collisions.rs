@@ -0,0 +1,13 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(as_ref)]
+enum Foo {
+    Bar,
+    Baz(i32),
+    BAR,
+    BAZ(i32),
+    BaR,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[common_field(id)]
+enum Entity {
+    User { id: u64 },
+    Group { id: u32 },
+}
+
+fn main() {}
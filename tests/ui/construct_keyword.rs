@@ -0,0 +1,11 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    #[expect_macro(construct)]
+    Crate(i32),
+    #[expect_macro(construct)]
+    Super(i32),
+}
+
+fn main() {}
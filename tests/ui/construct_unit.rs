@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    #[expect_macro(construct)]
+    Bar,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(deref)]
+enum Foo {
+    Bar(String),
+    Baz(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+enum Foo {
+    #[panic]
+    #[panic]
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use expect_macro_derive::Expect;
+
+trait ApproxEq {
+    fn approx_eq(&self, other: &Self) -> bool;
+}
+
+#[derive(Expect)]
+#[expect_macro(eq_trait = "ApproxEq", eq_impl)]
+enum Foo {
+    Bar(f64),
+}
+
+#[derive(Expect)]
+#[expect_macro(eq_method = "approx_eq")]
+enum Baz {
+    Qux(f64),
+}
+
+fn main() {}
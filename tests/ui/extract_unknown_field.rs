@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    #[extract(a, c)]
+    Bar { a: i32, b: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(impl_on = "Wrapper", as_ref)]
+enum Foo<T> {
+    Bar(T),
+}
+
+struct Wrapper(Foo<i32>);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(impl_on = "Wrapper", pin_projections)]
+enum Foo {
+    Bar(u32),
+}
+
+struct Wrapper(Foo);
+
+fn main() {}
@@ -0,0 +1,14 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    Bar(i32),
+}
+
+impl Foo {
+    fn expect_bar(&self) -> bool {
+        matches!(self, Foo::Bar(_))
+    }
+}
+
+fn main() {}
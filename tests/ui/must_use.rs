@@ -0,0 +1,30 @@
+#![deny(unused_must_use)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(into, as_ref)]
+enum Foo {
+    Bar(i32),
+    Qux,
+}
+
+#[derive(Expect)]
+#[expect_macro(module = "baz_ext", into, as_ref)]
+enum Baz {
+    Bar(i32),
+    Qux,
+}
+
+use baz_ext::BazExt;
+
+fn main() {
+    let foo = Foo::Bar(1);
+    foo.as_bar();
+    foo.expect_bar(1);
+
+    let baz = Baz::Bar(1);
+    baz.as_bar();
+    Baz::Qux.into_bar();
+    baz.expect_bar(1);
+}
@@ -0,0 +1,18 @@
+#![deny(unused_must_use)]
+
+use expect_macro_derive::Expect;
+
+// the panicking methods are used as assertions, so their result can be ignored.
+#[derive(Debug, Expect)]
+#[expect_macro(unwrap, take)]
+enum Foo {
+    #[panic]
+    Bar(i32),
+    Qux,
+}
+
+fn main() {
+    Foo::Bar(1).expect_bar(1);
+    Foo::Bar(1).unwrap_bar();
+    Foo::Bar(1).take_bar();
+}
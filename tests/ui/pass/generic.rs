@@ -0,0 +1,22 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+#[expect_macro(as_ref, as_mut, into)]
+enum Either<'a, L, R: Clone>
+where
+    L: 'a,
+{
+    Left(&'a L),
+    Right { value: R },
+}
+
+fn main() {
+    let left = 1;
+    let either = Either::<_, String>::Left(&left);
+    assert_eq!(either.as_left(), Some(&&1));
+    assert_eq!(either.into_left(), Some(&1));
+
+    let mut right: Either<i32, i32> = Either::Right { value: 2 };
+    *right.as_mut_right().unwrap() += 1;
+    assert_eq!(right.expect_right(3), Some(3));
+}
@@ -0,0 +1,15 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(module = "token_ext", as_discriminant)]
+enum Token {
+    Number(u32),
+    Word(String),
+}
+
+use token_ext::TokenExt;
+
+fn main() {
+    assert_eq!(Token::Number(1).as_discriminant(), Token::Number(2).as_discriminant());
+    assert_ne!(Token::Number(1).as_discriminant(), Token::Word("a".to_string()).as_discriminant());
+}
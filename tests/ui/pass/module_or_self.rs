@@ -0,0 +1,15 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(module = "job_ext", or_self)]
+enum Job {
+    Queued(u32),
+    Done,
+}
+
+use job_ext::JobExt;
+
+fn main() {
+    assert_eq!(Job::Queued(1).expect_queued_or_self(1), Ok(1));
+    assert_eq!(Job::Done.expect_queued_or_self(1), Err(Job::Done));
+}
@@ -0,0 +1,15 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(module = "response_ext", result_ok_variant = "Success")]
+enum Response {
+    Success(u32),
+    Failure(String),
+}
+
+use response_ext::ResponseExt;
+
+fn main() {
+    assert_eq!(Response::Success(1).into_result(), Ok(1));
+    assert_eq!(Response::Failure("a".to_string()).into_result(), Err(Response::Failure("a".to_string())));
+}
@@ -0,0 +1,16 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(module = "shape_ext", with_methods)]
+enum Shape {
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+}
+
+use shape_ext::ShapeExt;
+
+fn main() {
+    let circle = Shape::Circle(1.0).with_circle(2.0);
+    assert_eq!(circle, Shape::Circle(2.0));
+    assert_eq!(Shape::Circle(1.0).with_rect(1.0, 2.0), Shape::Rect { width: 1.0, height: 2.0 });
+}
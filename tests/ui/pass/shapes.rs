@@ -0,0 +1,20 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(all)]
+enum Shape {
+    Named { a: i32, b: String },
+    Unnamed(i32, String),
+    Unit,
+    #[panic]
+    Panicking(u8),
+}
+
+fn main() {
+    let named = Shape::Named { a: 1, b: "b".to_string() };
+    assert!(named.is_named());
+    assert_eq!(named.expect_named(1, "b".to_string()), Some((1, "b".to_string())));
+    assert_eq!(Shape::Unnamed(1, "b".to_string()).into_unnamed(), Some((1, "b".to_string())));
+    assert_eq!(Shape::Unit.expect_unit(), Some(()));
+    assert_eq!(Shape::Panicking(1).expect_panicking(1), 1);
+}
@@ -0,0 +1,25 @@
+use expect_macro_derive::Expect;
+
+// the variants with more than 12 fields need `#[return_struct]`, as the tuples don't implement the std traits,
+// like `Debug` or `PartialEq`.
+#[derive(Debug, Clone, PartialEq, Expect)]
+#[expect_macro(all, tuple_args, detailed)]
+enum Wide {
+    #[panic]
+    #[return_struct]
+    Many(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+    #[return_struct(derive(Debug, PartialEq))]
+    Compared(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+    Empty,
+}
+
+fn main() {
+    let many = Wide::Many(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+    let WideMany(a, .., m) = many.clone().expect_many((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13));
+    assert_eq!((a, m), (1, 13));
+    assert!(many.is_many());
+
+    let compared = Wide::Compared(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+    assert_eq!(compared.into_compared(), Some(WideCompared(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13)));
+    assert!(Wide::Empty.into_compared().is_none());
+}
@@ -0,0 +1,14 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(pin_projections)]
+enum State {
+    Running(u32),
+    Done,
+}
+
+impl Drop for State {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use std::marker::PhantomPinned;
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(pin_projections)]
+enum Foo {
+    Bar(u8, #[phantom] PhantomPinned),
+    Other(u8),
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn main() {
+    assert_unpin::<Foo>();
+}
@@ -0,0 +1,14 @@
+use std::marker::PhantomPinned;
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(pin_projections)]
+enum State {
+    Running(PhantomPinned),
+    Done,
+}
+
+impl Unpin for State {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(getters, predicate_accessors)]
+enum Foo {
+    Bar { a_b: u8 },
+    #[rename = "bar_a"]
+    BarA { b: u8 },
+    #[rename = "bar_if"]
+    BarIf,
+}
+
+fn main() {}
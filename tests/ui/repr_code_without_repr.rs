@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(repr_code)]
+enum Packet {
+    Ping,
+    Data(Vec<u8>),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(result_ok_variant = "Success")]
+enum Outcome {
+    Done(u32),
+    Failed(String),
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(ref_enum)]
+enum Foo {
+    #[return_struct(name = "FooRef")]
+    Bar(u8),
+}
+
+#[derive(Expect)]
+#[expect_macro(projection)]
+enum Qux {
+    #[return_struct(name = "QuxProjection")]
+    Bar(u8),
+}
+
+#[derive(Expect)]
+#[expect_macro(detailed)]
+enum Baz {
+    #[return_struct(name = "BazMatchResult")]
+    Bar(u8),
+}
+
+#[derive(Expect)]
+#[expect_macro(try_as)]
+enum Quux {
+    #[return_struct(name = "QuuxVariantMismatch")]
+    Bar(u8),
+}
+
+#[derive(Expect)]
+#[expect_macro(module = "corge_ext")]
+enum Corge {
+    #[return_struct(name = "CorgeExt")]
+    Bar(u8),
+}
+
+#[derive(Expect)]
+#[expect_macro(ref_enum)]
+enum Grault {
+    #[return_struct]
+    Ref(u8),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    #[return_struct(name = "Data")]
+    Bar(u8),
+    #[return_struct(name = "Data")]
+    Baz(u16),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    #[return_struct(name = "Bar Data")]
+    Bar(u8),
+}
+
+fn main() {}
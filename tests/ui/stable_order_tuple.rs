@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+enum Foo {
+    #[stable_order]
+    Bar(i32, i32),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(take)]
+enum Slot {
+    Full(String),
+    Reserved(u32),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(only(is, as_reff))]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Expect)]
+enum Wide {
+    Many(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+    Twelve(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+}
+
+fn main() {}
@@ -0,0 +1,65 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Expect)]
+#[expect_macro(variant_count)]
+enum Message<T> {
+    Value(T),
+    Pair { left: T, right: T },
+    Empty,
+}
+
+#[derive(Expect)]
+#[expect_macro(variant_count, module = "metric_ext")]
+enum Metric {
+    Count(u64),
+    Gauge(f64),
+}
+
+fn count<T>() -> usize {
+    Message::<T>::VARIANT_COUNT
+}
+
+const SLOTS: [u8; Message::<String>::VARIANT_COUNT] = [0; Message::<String>::VARIANT_COUNT];
+
+#[test]
+fn generic_enum() {
+    assert_eq!(Message::<u8>::VARIANT_COUNT, 3);
+    assert_eq!(count::<Vec<String>>(), 3);
+    assert_eq!(SLOTS.len(), 3);
+}
+
+#[test]
+fn with_the_methods_in_a_module() {
+    assert_eq!(Metric::VARIANT_COUNT, 2);
+}
+
+#[derive(Expect)]
+#[expect_macro(variant_count, assert_count = 2)]
+enum Checked {
+    On(bool),
+    Off,
+}
+
+#[test]
+fn matching_assert_count() {
+    assert_eq!(Checked::VARIANT_COUNT, 2);
+}
+
+#[derive(Expect)]
+#[expect_macro(variants_with_data, module = "event_ext")]
+enum Event<T> {
+    Start,
+    Data(T),
+    Empty(),
+    Move { x: i32, y: i32 },
+    Stop,
+}
+
+#[test]
+fn unit_variants_excluded() {
+    use event_ext::EventExt;
+
+    assert_eq!(Event::<u8>::variants_with_data(), ["Data", "Empty", "Move"]);
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, PartialEq, Expect)]
+#[expect_macro(variant_rank)]
+enum Task {
+    Urgent(String),
+    Normal { id: u32 },
+    Background,
+}
+
+#[test]
+fn ranks_follow_the_declaration_order() {
+    assert_eq!(Task::Urgent(String::new()).variant_rank(), 0);
+    assert_eq!(Task::Normal { id: 1 }.variant_rank(), 1);
+    assert_eq!(Task::Background.variant_rank(), 2);
+    assert_eq!(Task::by_variant(&Task::Background, &Task::Normal { id: 1 }), Ordering::Greater);
+    assert_eq!(Task::by_variant(&Task::Normal { id: 1 }, &Task::Normal { id: 2 }), Ordering::Equal);
+}
+
+#[test]
+fn sort_by_variant() {
+    let mut tasks = vec![
+        Task::Background,
+        Task::Normal { id: 1 },
+        Task::Urgent("a".to_string()),
+        Task::Normal { id: 2 },
+    ];
+    tasks.sort_by(Task::by_variant);
+    assert_eq!(tasks, vec![
+        Task::Urgent("a".to_string()),
+        Task::Normal { id: 1 },
+        Task::Normal { id: 2 },
+        Task::Background,
+    ]);
+}
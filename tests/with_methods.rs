@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+use expect_macro_derive::Expect;
+
+#[derive(Debug, Default, PartialEq, Expect)]
+#[expect_macro(with_methods)]
+enum Shape {
+    #[default]
+    Empty,
+    Circle(f64),
+    Rect { width: u32, height: u32 },
+}
+
+#[test]
+fn chained_with_calls() {
+    let shape = Shape::default().with_circle(1.5).with_rect(2, 3);
+    assert_eq!(shape, Shape::Rect { width: 2, height: 3 });
+    assert_eq!(shape.with_empty(), Shape::Empty);
+}
+
+#[test]
+fn replaces_the_same_variant() {
+    assert_eq!(Shape::Circle(1.0).with_circle(2.0).expect_circle(2.0), Some(2.0));
+}